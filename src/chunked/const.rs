@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Header fields a trailer section must not carry, as listed in RFC 9110 section 6.5.1.
+///
+/// They control message framing, routing, authentication, request modifiers, response
+/// control or content handling, all of which are decided before the body is read.
+pub const FORBIDDEN_TRAILER_FIELDS: &[&str] = &[
+    TRANSFER_ENCODING,
+    CONTENT_LENGTH,
+    "trailer",
+    TE,
+    CONNECTION,
+    UPGRADE,
+    HOST,
+    MAX_FORWARDS,
+    AUTHORIZATION,
+    PROXY_AUTHORIZATION,
+    WWW_AUTHENTICATE,
+    PROXY_AUTHENTICATE,
+    COOKIE,
+    SET_COOKIE,
+    CACHE_CONTROL,
+    EXPECT,
+    RANGE,
+    IF_MATCH,
+    IF_NONE_MATCH,
+    IF_MODIFIED_SINCE,
+    IF_UNMODIFIED_SINCE,
+    IF_RANGE,
+    LOCATION,
+    CONTENT_ENCODING,
+    CONTENT_TYPE,
+    CONTENT_RANGE,
+];
@@ -0,0 +1,17 @@
+use crate::*;
+
+/// Represents the decoding step of a `Transfer-Encoding: chunked` body.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ChunkedState {
+    /// Waiting for a chunk size line, optionally followed by chunk extensions.
+    #[default]
+    Size,
+    /// Reading chunk data, including the number of bytes still expected.
+    Data(usize),
+    /// Waiting for the CRLF that terminates the chunk data.
+    DataEnd,
+    /// Reading trailer header lines after the last chunk.
+    Trailer,
+    /// The terminating empty line has been read and the body is complete.
+    Done,
+}
@@ -0,0 +1,264 @@
+use crate::*;
+
+impl ChunkedDecoder {
+    /// Creates a new `ChunkedDecoder` that enforces the limits of the given configuration.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestConfig` - Configuration providing the body size and trailer limits.
+    ///
+    /// # Returns
+    ///
+    /// - `ChunkedDecoder` - A decoder waiting for the first chunk size line.
+    #[inline(always)]
+    pub fn new(config: RequestConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Checks whether the terminating chunk and trailer section have been decoded.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the chunked body is complete.
+    #[inline(always)]
+    pub fn is_done(&self) -> bool {
+        self.state == ChunkedState::Done
+    }
+
+    /// Gets the maximum length of a chunk size or trailer line.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The maximum line length in bytes.
+    #[inline(always)]
+    fn get_max_line_size(&self) -> usize {
+        self.config
+            .get_max_header_key_size()
+            .saturating_add(self.config.get_max_header_value_size())
+            .saturating_add(COLON_SPACE.len())
+    }
+
+    /// Takes the next chunk size, chunk end or trailer line from the input.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The unread input bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Option<Vec<u8>>, usize), RequestError>` - The completed line without
+    ///   its line ending, if any, and the number of input bytes consumed.
+    fn take_line(&mut self, input: &[u8]) -> Result<(Option<Vec<u8>>, usize), RequestError> {
+        let too_long: RequestError = match self.state {
+            ChunkedState::Trailer => {
+                RequestError::HeaderLineTooLong(HttpStatus::RequestHeaderFieldsTooLarge)
+            }
+            _ => RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest),
+        };
+        self.line
+            .take_line(input, self.get_max_line_size(), too_long)
+    }
+
+    /// Parses a chunk size line, ignoring any chunk extensions.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The chunk size line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the size is valid and within the body size limit.
+    fn parse_size_line(&mut self, line: &[u8]) -> Result<(), RequestError> {
+        let line_str: &str = std::str::from_utf8(line)
+            .map_err(|_| RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest))?;
+        let size_str: &str = line_str.split(SEMICOLON).next().unwrap_or_default().trim();
+        if size_str.is_empty() || !size_str.bytes().all(|byte: u8| byte.is_ascii_hexdigit()) {
+            return Err(RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest));
+        }
+        let size: usize = usize::from_str_radix(size_str, 16)
+            .map_err(|_| RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest))?;
+        if size == 0 {
+            self.state = ChunkedState::Trailer;
+            return Ok(());
+        }
+        let max_body_size: usize = self.config.get_max_body_size();
+        let body_size: usize =
+            self.body_size
+                .checked_add(size)
+                .ok_or(RequestError::ContentLengthTooLarge(
+                    HttpStatus::PayloadTooLarge,
+                ))?;
+        if body_size > max_body_size && max_body_size != DEFAULT_LOW_SECURITY_MAX_BODY_SIZE {
+            return Err(RequestError::ContentLengthTooLarge(
+                HttpStatus::PayloadTooLarge,
+            ));
+        }
+        self.body_size = body_size;
+        self.state = ChunkedState::Data(size);
+        Ok(())
+    }
+
+    /// Checks whether a header field must be dropped from a trailer section.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The lowercase header key.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the field is listed in `FORBIDDEN_TRAILER_FIELDS`.
+    #[inline(always)]
+    pub fn is_forbidden_trailer(key: &str) -> bool {
+        FORBIDDEN_TRAILER_FIELDS.contains(&key)
+    }
+
+    /// Parses a trailer header line and stores it with the trailers.
+    ///
+    /// Fields listed in `FORBIDDEN_TRAILER_FIELDS` count toward the limit but are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The trailer line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the trailer is valid and within the header limits.
+    fn parse_trailer_line(&mut self, line: &[u8]) -> Result<(), RequestError> {
+        self.trailer_count += 1;
+        Http::check_header_count(self.trailer_count, self.config.get_max_header_count())?;
+        let line_string: Cow<'_, str> = String::from_utf8_lossy(line);
+        let (key, value): (String, String) =
+            match Http::parse_header_line(&line_string, &self.config)? {
+                Some(header) => header,
+                None => return Ok(()),
+            };
+        if Self::is_forbidden_trailer(&key) {
+            return Ok(());
+        }
+        self.trailers.entry(key).or_default().push_back(value);
+        Ok(())
+    }
+
+    /// Handles a complete line according to the current decoding step.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the line is valid for the current step.
+    fn parse_line(&mut self, line: &[u8]) -> Result<(), RequestError> {
+        match self.state {
+            ChunkedState::Size => self.parse_size_line(line),
+            ChunkedState::DataEnd => {
+                if !line.is_empty() {
+                    return Err(RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest));
+                }
+                self.state = ChunkedState::Size;
+                Ok(())
+            }
+            ChunkedState::Trailer => {
+                if line.is_empty() {
+                    self.state = ChunkedState::Done;
+                    return Ok(());
+                }
+                self.parse_trailer_line(line)
+            }
+            ChunkedState::Data(_) | ChunkedState::Done => Ok(()),
+        }
+    }
+
+    /// Decodes as much of the input as possible, appending chunk data to the body.
+    ///
+    /// Decoding stops when the input is exhausted or the body is complete. Bytes that
+    /// belong to a following message are left unconsumed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw chunked bytes to decode.
+    /// - `&mut RequestBody` - The body that decoded chunk data is appended to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<usize, RequestError>` - The number of input bytes consumed, or an error
+    ///   if the encoding is malformed or exceeds the configured limits.
+    pub fn decode(&mut self, input: &[u8], body: &mut RequestBody) -> Result<usize, RequestError> {
+        let mut offset: usize = 0;
+        while offset < input.len() && !self.is_done() {
+            if let ChunkedState::Data(remaining) = self.state {
+                let size: usize = remaining.min(input.len() - offset);
+                body.extend_from_slice(&input[offset..offset + size]);
+                offset += size;
+                self.state = if size == remaining {
+                    ChunkedState::DataEnd
+                } else {
+                    ChunkedState::Data(remaining - size)
+                };
+                continue;
+            }
+            let (line_opt, consumed): (Option<Vec<u8>>, usize) =
+                self.take_line(&input[offset..])?;
+            offset += consumed;
+            if let Some(line) = line_opt {
+                self.parse_line(&line)?;
+            }
+        }
+        Ok(offset)
+    }
+}
+
+impl ChunkedEncoder {
+    /// Frames data as a single chunk.
+    ///
+    /// Empty data produces no bytes, because an empty chunk would end the body.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The chunk data.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The chunk size line, the data and the closing line ending.
+    pub fn encode_chunk(data: &[u8]) -> Vec<u8> {
+        if data.is_empty() {
+            return Vec::new();
+        }
+        let size_line: String = format!("{:x}", data.len());
+        let mut chunk: Vec<u8> =
+            Vec::with_capacity(size_line.len() + data.len() + HTTP_BR_BYTES.len() * 2);
+        chunk.extend_from_slice(size_line.as_bytes());
+        chunk.extend_from_slice(HTTP_BR_BYTES);
+        chunk.extend_from_slice(data);
+        chunk.extend_from_slice(HTTP_BR_BYTES);
+        chunk
+    }
+
+    /// Produces the terminating zero-size chunk followed by the trailer section.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseHeaders` - The trailer headers, which may be empty.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The last chunk, the trailer headers and the final empty line.
+    pub fn encode_last_chunk(trailers: &ResponseHeaders) -> Vec<u8> {
+        let mut last_chunk: String = String::with_capacity(DEFAULT_BUFFER_SIZE);
+        last_chunk.push('0');
+        last_chunk.push_str(HTTP_BR);
+        for (key, values) in trailers.iter() {
+            for value in values.iter() {
+                last_chunk.push_str(key);
+                last_chunk.push_str(COLON_SPACE);
+                last_chunk.push_str(value);
+                last_chunk.push_str(HTTP_BR);
+            }
+        }
+        last_chunk.push_str(HTTP_BR);
+        last_chunk.into_bytes()
+    }
+}
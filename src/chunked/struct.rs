@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Incremental decoder for `Transfer-Encoding: chunked` message bodies.
+///
+/// The decoder does not perform any I/O. Bytes are fed through `decode`,
+/// which reports how many of them were consumed, so the same decoder can be
+/// driven by buffered async readers or by in-memory byte buffers.
+#[derive(Clone, Debug, Default, Eq, Getter, PartialEq)]
+pub struct ChunkedDecoder {
+    /// Configuration providing the body and trailer limits.
+    #[get(skip)]
+    pub(super) config: RequestConfig,
+    /// The current decoding step.
+    #[get(type(copy))]
+    pub(super) state: ChunkedState,
+    /// The partial line collected across calls to `decode`.
+    #[get(skip)]
+    pub(super) line: LineBuffer,
+    /// The total number of decoded body bytes.
+    #[get(type(copy))]
+    pub(super) body_size: usize,
+    /// The number of trailer header lines read so far.
+    #[get(skip)]
+    pub(super) trailer_count: usize,
+    /// The trailer headers sent after the last chunk.
+    pub(super) trailers: RequestHeaders,
+}
+
+/// Encoder for `Transfer-Encoding: chunked` message bodies.
+///
+/// The encoder does not perform any I/O. It only frames body data as chunks
+/// and produces the terminating chunk with optional trailer headers.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, New, PartialEq, Serialize)]
+pub struct ChunkedEncoder;
@@ -0,0 +1,89 @@
+use crate::*;
+
+#[test]
+fn chunked_decoder_single_pass() {
+    let mut decoder: ChunkedDecoder = ChunkedDecoder::new(RequestConfig::default());
+    let mut body: RequestBody = Vec::new();
+    let input: &[u8] = b"5\r\nhello\r\n6\r\n world\r\n0\r\n\r\nGET / HTTP/1.1\r\n";
+    let consumed: usize = decoder.decode(input, &mut body).unwrap();
+    assert!(decoder.is_done());
+    assert_eq!(body, b"hello world");
+    assert_eq!(decoder.get_body_size(), 11);
+    assert_eq!(&input[consumed..], b"GET / HTTP/1.1\r\n");
+}
+
+#[test]
+fn chunked_decoder_byte_by_byte() {
+    let mut decoder: ChunkedDecoder = ChunkedDecoder::new(RequestConfig::default());
+    let mut body: RequestBody = Vec::new();
+    let input: &[u8] = b"4\r\nWiki\r\n5\r\npedia\r\nE\r\n in\r\n\r\nchunks.\r\n0\r\n\r\n";
+    for byte in input.chunks(1) {
+        assert_eq!(decoder.decode(byte, &mut body).unwrap(), 1);
+    }
+    assert!(decoder.is_done());
+    assert_eq!(body, b"Wikipedia in\r\n\r\nchunks.");
+}
+
+#[test]
+fn chunked_decoder_extensions_and_trailers() {
+    let mut decoder: ChunkedDecoder = ChunkedDecoder::new(RequestConfig::default());
+    let mut body: RequestBody = Vec::new();
+    let input: &[u8] =
+        b"3;name=value\r\nabc\r\n0;last\r\nX-Checksum: 900150983cd24fb0\r\nExpires: never\r\n\r\n";
+    decoder.decode(input, &mut body).unwrap();
+    assert!(decoder.is_done());
+    assert_eq!(body, b"abc");
+    let trailers: &RequestHeaders = decoder.get_trailers();
+    assert_eq!(
+        trailers.get("x-checksum").and_then(|values| values.front()),
+        Some(&"900150983cd24fb0".to_string())
+    );
+    assert_eq!(
+        trailers.get("expires").and_then(|values| values.front()),
+        Some(&"never".to_string())
+    );
+}
+
+#[test]
+fn chunked_decoder_max_body_size() {
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_body_size(8);
+    let mut decoder: ChunkedDecoder = ChunkedDecoder::new(config);
+    let mut body: RequestBody = Vec::new();
+    let result: Result<usize, RequestError> =
+        decoder.decode(b"5\r\nhello\r\n5\r\nworld\r\n0\r\n\r\n", &mut body);
+    assert_eq!(
+        result,
+        Err(RequestError::ContentLengthTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+}
+
+#[test]
+fn chunked_decoder_invalid_size() {
+    let mut decoder: ChunkedDecoder = ChunkedDecoder::new(RequestConfig::default());
+    let mut body: RequestBody = Vec::new();
+    let result: Result<usize, RequestError> = decoder.decode(b"zz\r\nhello\r\n", &mut body);
+    assert_eq!(
+        result,
+        Err(RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest))
+    );
+    let mut decoder: ChunkedDecoder = ChunkedDecoder::new(RequestConfig::default());
+    let result: Result<usize, RequestError> = decoder.decode(b"ffffffffffffffffff\r\n", &mut body);
+    assert_eq!(
+        result,
+        Err(RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest))
+    );
+}
+
+#[test]
+fn chunked_decoder_missing_data_terminator() {
+    let mut decoder: ChunkedDecoder = ChunkedDecoder::new(RequestConfig::default());
+    let mut body: RequestBody = Vec::new();
+    let result: Result<usize, RequestError> = decoder.decode(b"3\r\nabcdef\r\n", &mut body);
+    assert_eq!(
+        result,
+        Err(RequestError::InvalidChunkedEncoding(HttpStatus::BadRequest))
+    );
+}
@@ -0,0 +1,123 @@
+use crate::*;
+
+impl<R, W> ConnectionReader<R, W>
+where
+    R: AsyncReadHalf,
+    W: AsyncWriteHalf,
+{
+    /// Creates a new `ConnectionReader` for the given stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The stream to read requests from.
+    /// - `RequestConfig` - Configuration for security limits, buffer size and read timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `ConnectionReader` - A reader with an empty buffer.
+    #[inline(always)]
+    pub fn new(stream: ArcRwLockStream<R, W>, config: RequestConfig) -> Self {
+        Self {
+            stream,
+            config,
+            parser: RequestParser::new(config),
+            buffer: Vec::with_capacity(config.get_buffer_size()),
+            closed: false,
+        }
+    }
+
+    /// Checks whether the connection can be used for further requests.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the connection is still open for requests.
+    #[inline(always)]
+    pub fn is_keep_alive(&self) -> bool {
+        !self.closed
+    }
+
+    /// Marks the connection as closed so no further requests are read.
+    #[inline(always)]
+    pub fn close(&mut self) {
+        self.closed = true;
+    }
+
+    /// Takes the bytes that were read from the stream but not parsed yet.
+    ///
+    /// This is used when the connection switches protocols, for example after
+    /// a WebSocket upgrade, and the buffered bytes belong to the new protocol.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The unparsed bytes.
+    #[inline(always)]
+    pub fn take_buffer(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /// Reads requests from the buffer and stream until one is complete.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, RequestError>` - The next request, `None` if the client
+    ///   closed the connection between requests, or an error.
+    async fn read_request(&mut self) -> Result<Option<Request>, RequestError> {
+        loop {
+            if !self.buffer.is_empty() {
+                match self.parser.feed(&self.buffer)? {
+                    RequestParseStatus::Complete(request, consumed) => {
+                        self.buffer.drain(..consumed);
+                        return Ok(Some(*request));
+                    }
+                    RequestParseStatus::Partial => self.buffer.clear(),
+                }
+            }
+            self.buffer.reserve(self.config.get_buffer_size());
+            let size: usize = self
+                .stream
+                .read_mut()
+                .await
+                .read_buf(&mut self.buffer)
+                .await?;
+            if size == 0 {
+                self.closed = true;
+                if self.parser.is_idle() {
+                    return Ok(None);
+                }
+                return Err(RequestError::ClientClosedConnection(HttpStatus::BadRequest));
+            }
+        }
+    }
+
+    /// Reads the next request from the connection.
+    ///
+    /// Bytes read past the end of a request are kept for the next call. After a
+    /// request that disables keep-alive, according to its `Connection` header and
+    /// HTTP version, no further requests are read.
+    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, RequestError>` - The next request, `None` if the
+    ///   connection is closed, or an error.
+    pub async fn next_request(&mut self) -> Result<Option<Request>, RequestError> {
+        if self.closed {
+            return Ok(None);
+        }
+        let timeout_ms: u64 = self.config.get_read_timeout_ms();
+        let result: Result<Option<Request>, RequestError> =
+            if timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
+                self.read_request().await
+            } else {
+                let duration: Duration = Duration::from_millis(timeout_ms);
+                timeout(duration, self.read_request())
+                    .await
+                    .unwrap_or_else(|elapsed: Elapsed| Err(elapsed.into()))
+            };
+        match &result {
+            Ok(Some(request)) if request.is_enable_keep_alive() => {}
+            _ => self.closed = true,
+        }
+        result
+    }
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+/// Per-connection HTTP/1.x request reader.
+///
+/// Keeps its read buffer and parser state across requests, so bytes read past
+/// the end of one request are used for the next one instead of being dropped.
+#[derive(Debug, Getter)]
+pub struct ConnectionReader<R, W> {
+    /// The stream requests are read from.
+    pub(super) stream: ArcRwLockStream<R, W>,
+    /// Configuration for security limits, buffer size and read timeout.
+    #[get(type(copy))]
+    pub(super) config: RequestConfig,
+    /// The parser holding the partially read request.
+    #[get(skip)]
+    pub(super) parser: RequestParser,
+    /// Bytes read from the stream that have not been parsed yet.
+    pub(super) buffer: Vec<u8>,
+    /// Whether the connection must not be used for further requests.
+    #[get(type(copy))]
+    pub(super) closed: bool,
+}
@@ -0,0 +1,107 @@
+use crate::*;
+
+use tokio::{
+    io::{DuplexStream, duplex},
+    net::TcpListener,
+};
+
+async fn connect_pair() -> (TcpStream, ArcRwLockStream) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: std::net::SocketAddr = listener.local_addr().unwrap();
+    let client: TcpStream = TcpStream::connect(address).await.unwrap();
+    let (server, _): (TcpStream, std::net::SocketAddr) = listener.accept().await.unwrap();
+    (client, ArcRwLockStream::from_stream(server))
+}
+
+#[tokio::test]
+async fn connection_reader_pipelined_requests() {
+    let (mut client, stream): (TcpStream, ArcRwLockStream) = connect_pair().await;
+    client
+        .write_all(
+            b"POST /first HTTP/1.1\r\nContent-Length: 3\r\n\r\nabcGET /second HTTP/1.1\r\n\r\n",
+        )
+        .await
+        .unwrap();
+    let mut reader: ConnectionReader<OwnedReadHalf, OwnedWriteHalf> =
+        ConnectionReader::new(stream, RequestConfig::default());
+    let first: Request = reader.next_request().await.unwrap().unwrap();
+    assert_eq!(first.get_path(), "/first");
+    assert_eq!(first.get_body(), b"abc");
+    let second: Request = reader.next_request().await.unwrap().unwrap();
+    assert_eq!(second.get_path(), "/second");
+    assert!(reader.is_keep_alive());
+    drop(client);
+    assert_eq!(reader.next_request().await.unwrap(), None);
+    assert!(!reader.is_keep_alive());
+}
+
+#[tokio::test]
+async fn connection_reader_http1_0_closes() {
+    let (mut client, stream): (TcpStream, ArcRwLockStream) = connect_pair().await;
+    client
+        .write_all(b"GET /a HTTP/1.0\r\n\r\nGET /b HTTP/1.0\r\n\r\n")
+        .await
+        .unwrap();
+    let mut reader: ConnectionReader<OwnedReadHalf, OwnedWriteHalf> =
+        ConnectionReader::new(stream, RequestConfig::default());
+    let request: Request = reader.next_request().await.unwrap().unwrap();
+    assert_eq!(request.get_path(), "/a");
+    assert!(!reader.is_keep_alive());
+    assert_eq!(reader.next_request().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn connection_reader_http1_0_keep_alive() {
+    let (mut client, stream): (TcpStream, ArcRwLockStream) = connect_pair().await;
+    client
+        .write_all(b"GET /a HTTP/1.0\r\nConnection: keep-alive\r\n\r\nGET /b HTTP/1.1\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    let mut reader: ConnectionReader<OwnedReadHalf, OwnedWriteHalf> =
+        ConnectionReader::new(stream, RequestConfig::default());
+    assert_eq!(
+        reader.next_request().await.unwrap().unwrap().get_path(),
+        "/a"
+    );
+    assert!(reader.is_keep_alive());
+    assert_eq!(
+        reader.next_request().await.unwrap().unwrap().get_path(),
+        "/b"
+    );
+    assert!(!reader.is_keep_alive());
+}
+
+#[tokio::test]
+async fn connection_reader_closed_mid_request() {
+    let (mut client, stream): (TcpStream, ArcRwLockStream) = connect_pair().await;
+    client.write_all(b"GET /a HTTP/1.1\r\nHost").await.unwrap();
+    drop(client);
+    let mut reader: ConnectionReader<OwnedReadHalf, OwnedWriteHalf> =
+        ConnectionReader::new(stream, RequestConfig::default());
+    assert_eq!(
+        reader.next_request().await,
+        Err(RequestError::ClientClosedConnection(HttpStatus::BadRequest))
+    );
+}
+
+#[tokio::test]
+async fn connection_reader_in_memory_stream() {
+    let (mut client, server): (DuplexStream, DuplexStream) = duplex(1024);
+    client
+        .write_all(b"GET /a HTTP/1.1\r\n\r\nGET /b HTTP/1.1\r\n\r\n")
+        .await
+        .unwrap();
+    drop(client);
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(server);
+    let mut reader: ConnectionReader<ReadHalf<DuplexStream>, WriteHalf<DuplexStream>> =
+        ConnectionReader::new(stream, RequestConfig::default());
+    assert_eq!(
+        reader.next_request().await.unwrap().unwrap().get_path(),
+        "/a"
+    );
+    assert_eq!(
+        reader.next_request().await.unwrap().unwrap().get_path(),
+        "/b"
+    );
+    assert_eq!(reader.next_request().await.unwrap(), None);
+}
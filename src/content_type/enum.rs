@@ -25,6 +25,10 @@ pub enum ContentType {
     ///
     /// For form data submission.
     FormUrlEncoded,
+    /// `multipart/form-data` content type.
+    ///
+    /// For form submissions with file uploads.
+    MultipartFormData,
     /// Unknown content type.
     ///
     /// For unrecognized content types.
@@ -121,7 +121,7 @@ impl ContentType {
             Self::TextPlain => Self::get_text_plain(data),
             Self::TextHtml => Self::get_text_html(data),
             Self::FormUrlEncoded => Self::get_form_url_encoded(data),
-            Self::Unknown => Self::get_binary(data),
+            Self::MultipartFormData | Self::Unknown => Self::get_binary(data),
         }
     }
 
@@ -205,6 +205,9 @@ impl FromStr for ContentType {
             TEXT_PLAIN => Ok(Self::TextPlain),
             TEXT_HTML => Ok(Self::TextHtml),
             FORM_URLENCODED => Ok(Self::FormUrlEncoded),
+            value if value.split(SEMICOLON).next().map(str::trim) == Some(MULTIPART_FORM_DATA) => {
+                Ok(Self::MultipartFormData)
+            }
             _ => Ok(Self::Unknown),
         }
     }
@@ -149,6 +149,7 @@ fn test_content_type_pattern_matching() {
         ContentType::TextPlain => panic!("Should not match TextPlain"),
         ContentType::TextHtml => panic!("Should not match TextHtml"),
         ContentType::FormUrlEncoded => panic!("Should not match FormUrlEncoded"),
+        ContentType::MultipartFormData => panic!("Should not match MultipartFormData"),
         ContentType::Unknown => panic!("Should not match Unknown"),
     }
 }
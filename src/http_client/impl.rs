@@ -0,0 +1,209 @@
+use crate::*;
+
+impl Default for HttpClient {
+    /// Creates a new `HttpClient` with the default policy.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A client with the default request limits that does not follow redirects.
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            request_config: RequestConfig::default(),
+            redirect: false,
+            max_redirect_times: DEFAULT_MAX_REDIRECT_TIMES,
+        }
+    }
+}
+
+impl HttpClient {
+    /// Checks whether a status code asks the client to follow the `Location` header.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStatusCode` - The response status code.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the status is 301, 302, 303, 307 or 308.
+    #[inline(always)]
+    fn is_redirect_status(status_code: ResponseStatusCode) -> bool {
+        [
+            HttpStatus::MovedPermanently,
+            HttpStatus::Found,
+            HttpStatus::SeeOther,
+            HttpStatus::TemporaryRedirect,
+            HttpStatus::PermanentRedirect,
+        ]
+        .iter()
+        .any(|status: &HttpStatus| status.code() == status_code)
+    }
+
+    /// Gets the absolute URL a request is sent to.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Request` - The request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Url, RequestError>` - The URL, or an error if the request has no valid host.
+    fn get_request_url(request: &Request) -> Result<Url, RequestError> {
+        let host: RequestHost = request
+            .try_get_header_back(HOST)
+            .unwrap_or_else(|| request.get_host().clone());
+        if host.is_empty() {
+            return Err(RequestError::InvalidUrl(HttpStatus::BadRequest));
+        }
+        Url::parse(&format!(
+            "{HTTP_LOWERCASE}://{host}{}",
+            request.get_target()
+        ))
+        .map_err(|_| RequestError::InvalidUrl(HttpStatus::BadRequest))
+    }
+
+    /// Checks whether two URLs share the same scheme, host and port.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Url` - The first URL.
+    /// - `&Url` - The second URL.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether both URLs have the same origin.
+    #[inline(always)]
+    fn is_same_origin(left: &Url, right: &Url) -> bool {
+        left.scheme() == right.scheme()
+            && left.host_str() == right.host_str()
+            && left.port_or_known_default() == right.port_or_known_default()
+    }
+
+    /// Sends a request on a new connection and reads the response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Request` - The request to send.
+    /// - `&Url` - The URL the request is sent to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Response, RequestError>` - The response or an error.
+    async fn send_once(&self, request: &Request, url: &Url) -> Result<Response, RequestError> {
+        let host: &str = url
+            .host_str()
+            .ok_or(RequestError::InvalidUrl(HttpStatus::BadRequest))?;
+        let port: u16 = url.port_or_known_default().unwrap_or(DEFAULT_HTTP_PORT);
+        let tcp_stream: TcpStream = TcpStream::connect((host, port))
+            .await
+            .map_err(|_| RequestError::TcpStreamConnect(HttpStatus::BadGateway))?;
+        let stream: ArcRwLockStream = ArcRwLockStream::from_stream(tcp_stream);
+        request.check_headers()?;
+        stream.try_send(request.build()).await?;
+        stream.try_flush().await?;
+        Response::from_stream(&stream, request.get_method(), &self.request_config).await
+    }
+
+    /// Rewrites a request to follow a redirect response.
+    ///
+    /// A 303 turns every method except HEAD into a body-less GET, and a 301 or 302 does
+    /// the same for POST. The `Authorization` header is removed when the redirect leaves
+    /// the origin.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Request` - The request to rewrite.
+    /// - `ResponseStatusCode` - The redirect status code.
+    /// - `&Url` - The URL of the previous hop.
+    /// - `&Url` - The redirect target.
+    fn apply_redirect(
+        request: &mut Request,
+        status_code: ResponseStatusCode,
+        from: &Url,
+        to: &Url,
+    ) {
+        let is_see_other: bool = status_code == HttpStatus::SeeOther.code();
+        let is_legacy_redirect: bool = status_code == HttpStatus::MovedPermanently.code()
+            || status_code == HttpStatus::Found.code();
+        let rewrite_to_get: bool = (is_see_other && !request.get_method().is_head())
+            || (is_legacy_redirect && request.get_method().is_post());
+        if rewrite_to_get {
+            request.set_method(Method::Get);
+            request.set_body(Vec::new());
+            let headers: &mut RequestHeaders = request.get_mut_headers();
+            headers.remove(CONTENT_LENGTH);
+            headers.remove(CONTENT_TYPE);
+            headers.remove(TRANSFER_ENCODING);
+        }
+        if !Self::is_same_origin(from, to) {
+            request.get_mut_headers().remove(AUTHORIZATION);
+        }
+        let url: HttpUrlComponents = HttpUrlComponents {
+            protocol: to.scheme().to_string(),
+            host: to.host_str().map(str::to_string),
+            port: to.port(),
+            path: Some(to.path().to_string()),
+            query: to.query().map(str::to_string),
+            fragment: None,
+        };
+        request.set_url(&url);
+    }
+
+    /// Sends a request and reads the response, following redirects if enabled.
+    ///
+    /// Only `http` URLs are supported. Each redirect target is resolved against the URL
+    /// of the previous hop. A loop is detected when the same method is sent to the same
+    /// URL twice, so a post-redirect-get back to the submitted URL is followed.
+    ///
+    /// # Arguments
+    ///
+    /// - `Request` - The request to send. Its host or `Host` header selects the server.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Response, RequestError>` - The final response, or an error if the server
+    ///   cannot be reached, a redirect is received while redirects are disabled, the
+    ///   redirect limit is exceeded, a redirect target is invalid or a redirect loop is
+    ///   detected.
+    pub async fn send(&self, mut request: Request) -> Result<Response, RequestError> {
+        if !request.has_header(CONNECTION) {
+            let mut values: RequestHeadersValue = VecDeque::with_capacity(1);
+            values.push_back(CLOSE.to_string());
+            request
+                .get_mut_headers()
+                .insert(CONNECTION.to_string(), values);
+        }
+        let mut url: Url = Self::get_request_url(&request)?;
+        let mut visited: HashSet<(String, String)> = HashSet::new();
+        visited.insert((request.get_method().to_string(), url.to_string()));
+        let mut redirect_times: usize = 0;
+        loop {
+            let response: Response = self.send_once(&request, &url).await?;
+            let status_code: ResponseStatusCode = response.get_status_code();
+            let location: Option<ResponseHeadersValueItem> = response.try_get_header_back(LOCATION);
+            let location: String = match location {
+                Some(location) if Self::is_redirect_status(status_code) => location,
+                _ => return Ok(response),
+            };
+            if !self.redirect {
+                return Err(RequestError::NeedOpenRedirect(HttpStatus::BadGateway));
+            }
+            redirect_times += 1;
+            if redirect_times > self.max_redirect_times {
+                return Err(RequestError::MaxRedirectTimes(HttpStatus::BadGateway));
+            }
+            let mut next_url: Url = url
+                .join(location.trim())
+                .map_err(|_| RequestError::RedirectInvalidUrl(HttpStatus::BadGateway))?;
+            next_url.set_fragment(None);
+            if next_url.scheme() != HTTP_LOWERCASE || next_url.host_str().is_none() {
+                return Err(RequestError::RedirectInvalidUrl(HttpStatus::BadGateway));
+            }
+            Self::apply_redirect(&mut request, status_code, &url, &next_url);
+            if !visited.insert((request.get_method().to_string(), next_url.to_string())) {
+                return Err(RequestError::RedirectUrlDeadLoop(HttpStatus::LoopDetected));
+            }
+            url = next_url;
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+/// Minimal asynchronous HTTP/1.1 client.
+///
+/// Each request is sent on a new TCP connection with `Connection: close`.
+/// Redirects are only followed when enabled.
+#[derive(Clone, Copy, Data, Debug, Deserialize, DisplayDebug, Eq, New, PartialEq, Serialize)]
+#[serde(default)]
+pub struct HttpClient {
+    /// Configuration for the limits and read timeout applied to responses.
+    #[get(type(copy))]
+    #[set]
+    pub(super) request_config: RequestConfig,
+    /// Whether redirect responses are followed.
+    #[get(type(copy))]
+    #[set]
+    pub(super) redirect: bool,
+    /// Maximum number of redirects followed for one request.
+    #[get(type(copy))]
+    #[set]
+    pub(super) max_redirect_times: usize,
+}
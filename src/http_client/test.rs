@@ -0,0 +1,167 @@
+use crate::*;
+
+use tokio::net::{TcpListener, TcpStream};
+
+type Routes = fn(&Request) -> String;
+
+async fn spawn_server(routes: Routes) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: String = listener.local_addr().unwrap().to_string();
+    let received: Arc<Mutex<Vec<Request>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone: Arc<Mutex<Vec<Request>>> = received.clone();
+    tokio::spawn(async move {
+        loop {
+            let (tcp_stream, _): (TcpStream, _) = listener.accept().await.unwrap();
+            let stream: ArcRwLockStream = ArcRwLockStream::from_stream(tcp_stream);
+            let request: Request = Request::http_from_stream(&stream, &RequestConfig::default())
+                .await
+                .unwrap();
+            let response: String = routes(&request);
+            received_clone.lock().await.push(request);
+            stream.send(response).await;
+        }
+    });
+    (address, received)
+}
+
+fn redirect(status: &str, location: &str) -> String {
+    format!("HTTP/1.1 {status}\r\nLocation: {location}\r\nContent-Length: 0\r\n\r\n")
+}
+
+fn ok(body: &str) -> String {
+    format!("HTTP/1.1 200 OK\r\n\r\n{body}")
+}
+
+fn request_to(address: &str, path: &str) -> RequestBuilder {
+    let url: HttpUrlComponents =
+        HttpUrlComponents::parse(format!("http://{address}{path}")).unwrap();
+    let mut builder: RequestBuilder = RequestBuilder::new();
+    builder.url(&url);
+    builder
+}
+
+#[tokio::test]
+async fn http_client_follows_see_other_as_get() {
+    let (address, received): (String, Arc<Mutex<Vec<Request>>>) =
+        spawn_server(|request: &Request| match request.get_path().as_str() {
+            "/start" => redirect("303 See Other", "/next?step=2"),
+            _ => ok("done"),
+        })
+        .await;
+    let request: Request = request_to(&address, "/start")
+        .method(Method::Post)
+        .header(AUTHORIZATION, "Bearer token")
+        .unwrap()
+        .body("payload")
+        .build();
+    assert_eq!(
+        HttpClient::default().send(request.clone()).await,
+        Err(RequestError::NeedOpenRedirect(HttpStatus::BadGateway))
+    );
+    let mut client: HttpClient = HttpClient::default();
+    client.set_redirect(true);
+    let response: Response = client.send(request).await.unwrap();
+    assert_eq!(response.get_body(), b"done");
+    let received: Vec<Request> = received.lock().await.clone();
+    let last: &Request = received.last().unwrap();
+    assert_eq!(last.get_method(), &Method::Get);
+    assert_eq!(last.get_path(), "/next");
+    assert_eq!(last.get_query("step"), "2");
+    assert!(last.get_body().is_empty());
+    assert_eq!(last.get_header_back(AUTHORIZATION), "Bearer token");
+}
+
+#[tokio::test]
+async fn http_client_cross_origin_temporary_redirect() {
+    let (target, received): (String, Arc<Mutex<Vec<Request>>>) =
+        spawn_server(|_: &Request| ok("moved")).await;
+    let location: String = format!("http://{target}/landing");
+    let (address, _): (String, Arc<Mutex<Vec<Request>>>) = spawn_server(|request: &Request| {
+        redirect(
+            "307 Temporary Redirect",
+            &request.get_header_back("x-location"),
+        )
+    })
+    .await;
+    let request: Request = request_to(&address, "/old")
+        .method(Method::Put)
+        .header(AUTHORIZATION, "Bearer token")
+        .unwrap()
+        .header("x-location", &location)
+        .unwrap()
+        .body("payload")
+        .build();
+    let mut client: HttpClient = HttpClient::default();
+    client.set_redirect(true);
+    let response: Response = client.send(request).await.unwrap();
+    assert_eq!(response.get_body(), b"moved");
+    let received: Vec<Request> = received.lock().await.clone();
+    assert_eq!(received[0].get_method(), &Method::Put);
+    assert_eq!(received[0].get_body(), b"payload");
+    assert_eq!(received[0].get_host(), &target);
+    assert!(!received[0].has_header(AUTHORIZATION));
+}
+
+#[tokio::test]
+async fn http_client_redirect_errors() {
+    let (address, _): (String, Arc<Mutex<Vec<Request>>>) =
+        spawn_server(|request: &Request| match request.get_path().as_str() {
+            "/a" => redirect("302 Found", "/b"),
+            "/b" => redirect("301 Moved Permanently", "/a"),
+            "/ftp" => redirect("302 Found", "ftp://example.com/file"),
+            path => {
+                let next: usize = path.trim_start_matches("/n/").parse::<usize>().unwrap() + 1;
+                redirect("302 Found", &format!("/n/{next}"))
+            }
+        })
+        .await;
+    let mut client: HttpClient = HttpClient::default();
+    client.set_redirect(true).set_max_redirect_times(3);
+    assert_eq!(
+        client.send(request_to(&address, "/a").build()).await,
+        Err(RequestError::RedirectUrlDeadLoop(HttpStatus::LoopDetected))
+    );
+    assert_eq!(
+        client.send(request_to(&address, "/n/0").build()).await,
+        Err(RequestError::MaxRedirectTimes(HttpStatus::BadGateway))
+    );
+    assert_eq!(
+        client.send(request_to(&address, "/ftp").build()).await,
+        Err(RequestError::RedirectInvalidUrl(HttpStatus::BadGateway))
+    );
+}
+
+#[tokio::test]
+async fn http_client_connect_failure() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: String = listener.local_addr().unwrap().to_string();
+    drop(listener);
+    assert_eq!(
+        HttpClient::default()
+            .send(request_to(&address, "/").build())
+            .await,
+        Err(RequestError::TcpStreamConnect(HttpStatus::BadGateway))
+    );
+}
+
+#[tokio::test]
+async fn http_client_post_redirect_get_to_same_url() {
+    let (address, received): (String, Arc<Mutex<Vec<Request>>>) =
+        spawn_server(|request: &Request| match request.get_method() {
+            Method::Post => redirect("303 See Other", "/form"),
+            _ => ok("form"),
+        })
+        .await;
+    let mut client: HttpClient = HttpClient::default();
+    client.set_redirect(true);
+    let request: Request = request_to(&address, "/form")
+        .method(Method::Post)
+        .body("name=value")
+        .build();
+    let response: Response = client.send(request).await.unwrap();
+    assert_eq!(response.get_body(), b"form");
+    let received: Vec<Request> = received.lock().await.clone();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[1].get_method(), &Method::Get);
+    assert_eq!(received[1].get_path(), "/form");
+}
@@ -10,56 +10,88 @@ mod any;
 mod arc_mutex;
 mod arc_rwlock;
 mod box_rwlock;
+mod chunked;
+mod connection;
 mod content_type;
 mod cookie;
 mod file_extension;
 mod hash_map_xx_hash3_64;
 mod hash_set_xx_hash3_64;
+mod http_client;
 mod http_status;
 mod http_url;
 mod http_version;
+mod line_buffer;
 mod methods;
+mod multipart;
 mod protocol;
+mod random;
 mod rc_rwlock;
 mod request;
+mod request_body;
+mod request_parser;
 mod response;
+mod response_parser;
+mod response_writer;
+mod sse;
 mod stream;
 mod upgrade_type;
+mod websocket_client;
+mod websocket_deflate;
 mod websocket_frame;
+mod websocket_heartbeat;
+mod websocket_message;
+mod websocket_server;
 
 pub use {
-    any::*, arc_mutex::*, arc_rwlock::*, box_rwlock::*, content_type::*, cookie::*,
-    file_extension::*, hash_map_xx_hash3_64::*, hash_set_xx_hash3_64::*, http_status::*,
-    http_url::*, http_version::*, methods::*, protocol::*, rc_rwlock::*, request::*, response::*,
-    stream::*, upgrade_type::*, websocket_frame::*,
+    any::*, arc_mutex::*, arc_rwlock::*, box_rwlock::*, chunked::*, connection::*, content_type::*,
+    cookie::*, file_extension::*, hash_map_xx_hash3_64::*, hash_set_xx_hash3_64::*, http_client::*,
+    http_status::*, http_url::*, http_version::*, methods::*, multipart::*, protocol::*,
+    rc_rwlock::*, request::*, request_body::*, request_parser::*, response::*, response_parser::*,
+    response_writer::*, sse::*, stream::*, upgrade_type::*, websocket_client::*,
+    websocket_deflate::*, websocket_frame::*, websocket_heartbeat::*, websocket_message::*,
+    websocket_server::*,
 };
 
 pub use {http_compress::*, http_constant::*, serde_json, tokio};
 
+use {line_buffer::*, random::*};
+
 use std::{
     any::Any,
+    borrow::Cow,
     collections::{HashMap, HashSet, VecDeque},
+    ffi::OsStr,
     fmt::{self, Debug, Display},
     hash::Hash,
     io::ErrorKind,
     net::IpAddr,
     num::ParseIntError,
+    path::{Component, Components, Path, PathBuf},
     rc::Rc,
     result::Result,
     str::{FromStr, SplitWhitespace},
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use {
     core::hash::BuildHasherDefault,
+    flate2::{Decompress, FlushDecompress, Status},
     lombok_macros::*,
     serde::{Deserialize, Serialize, de::DeserializeOwned},
     tokio::{
-        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
-        net::TcpStream,
-        sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
+        fs::File,
+        io::{
+            AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt, ReadHalf, SeekFrom,
+            Take, WriteHalf, split,
+        },
+        net::{
+            TcpStream,
+            tcp::{OwnedReadHalf, OwnedWriteHalf},
+        },
+        sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, mpsc::Receiver},
         time::{error::Elapsed, timeout},
     },
-    url::{ParseError, Url},
+    url::{ParseError, Url, form_urlencoded},
 };
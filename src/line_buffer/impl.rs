@@ -0,0 +1,48 @@
+use crate::*;
+
+impl LineBuffer {
+    /// Checks whether no bytes of a line are pending.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the pending line is empty.
+    #[inline(always)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.line.is_empty()
+    }
+
+    /// Collects bytes into the pending line until a line feed is found.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The unread input bytes.
+    /// - `usize` - The maximum line length in bytes, without the line ending.
+    /// - `RequestError` - The error returned when the line exceeds the maximum length.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Option<Vec<u8>>, usize), RequestError>` - The completed line without
+    ///   its line ending, if any, and the number of input bytes consumed.
+    pub(crate) fn take_line(
+        &mut self,
+        input: &[u8],
+        max_size: usize,
+        too_long: RequestError,
+    ) -> Result<(Option<Vec<u8>>, usize), RequestError> {
+        let line_end_opt: Option<usize> = input.iter().position(|byte| *byte == BR_U8);
+        let consumed: usize = line_end_opt.map_or(input.len(), |index: usize| index + 1);
+        let line_part: &[u8] = line_end_opt.map_or(input, |index: usize| &input[..index]);
+        if self.line.len() + line_part.len() > max_size {
+            return Err(too_long);
+        }
+        self.line.extend_from_slice(line_part);
+        if line_end_opt.is_none() {
+            return Ok((None, consumed));
+        }
+        let mut line: Vec<u8> = std::mem::take(&mut self.line);
+        if line.last() == Some(&HTTP_BR_BYTES[0]) {
+            line.pop();
+        }
+        Ok((Some(line), consumed))
+    }
+}
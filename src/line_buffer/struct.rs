@@ -0,0 +1,11 @@
+use crate::*;
+
+/// Accumulates a CRLF or LF terminated line across partial inputs.
+///
+/// Shared by the request, response and chunked body parsers, which only differ in
+/// their line limits and in the error reported when a line is too long.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct LineBuffer {
+    /// The bytes of the pending line collected so far.
+    pub(super) line: Vec<u8>,
+}
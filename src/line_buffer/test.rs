@@ -0,0 +1,22 @@
+use crate::*;
+
+#[test]
+fn line_buffer_partial_lines_and_limit() {
+    let too_long: RequestError = RequestError::HeaderLineTooLong(HttpStatus::BadRequest);
+    let mut buffer: LineBuffer = LineBuffer::default();
+    assert_eq!(buffer.take_line(b"ab", 8, too_long.clone()), Ok((None, 2)));
+    assert!(!buffer.is_empty());
+    assert_eq!(
+        buffer.take_line(b"c\r\nrest", 8, too_long.clone()),
+        Ok((Some(b"abc".to_vec()), 3))
+    );
+    assert!(buffer.is_empty());
+    assert_eq!(
+        buffer.take_line(b"plain\n", 8, too_long.clone()),
+        Ok((Some(b"plain".to_vec()), 6))
+    );
+    assert_eq!(
+        buffer.take_line(b"too long line", 8, too_long.clone()),
+        Err(too_long)
+    );
+}
@@ -0,0 +1,20 @@
+/// The `Content-Type` for multipart form submissions.
+pub const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+
+/// The `Content-Type` parameter carrying the multipart boundary.
+pub const MULTIPART_BOUNDARY: &str = "boundary";
+
+/// The `Content-Disposition` parameter carrying the form field name.
+pub const MULTIPART_NAME: &str = "name";
+
+/// The prefix of every multipart boundary delimiter line.
+pub const MULTIPART_DELIMITER_PREFIX: &str = "--";
+
+/// The `Content-Type` for responses carrying several byte ranges.
+pub const MULTIPART_BYTERANGES: &str = "multipart/byteranges";
+
+/// The prefix of generated multipart boundaries.
+pub const MULTIPART_BOUNDARY_PREFIX: &str = "HttpTypeBoundary";
+
+/// The size of the buffer used when streaming file parts.
+pub const MULTIPART_FILE_BUFFER_SIZE: usize = 8192;
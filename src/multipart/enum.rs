@@ -0,0 +1,12 @@
+use crate::*;
+
+/// The content of a part assembled by `MultipartBuilder`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum MultipartSource {
+    /// Content held in memory.
+    Bytes(Vec<u8>),
+    /// The whole content of a file, read when the body is built or sent.
+    File(String),
+    /// The inclusive byte range `start..=end` of a file.
+    FileRange(String, u64, u64),
+}
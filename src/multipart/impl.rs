@@ -0,0 +1,880 @@
+use crate::*;
+
+impl MultipartPart {
+    /// Gets the part content as a string.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The content decoded as UTF-8, with invalid sequences replaced.
+    #[inline(always)]
+    pub fn get_body_string(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Checks whether the part is a file upload.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the part has a file name.
+    #[inline(always)]
+    pub fn is_file(&self) -> bool {
+        self.filename.is_some()
+    }
+}
+
+impl Multipart {
+    /// Finds a byte sequence in data, starting at an offset.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The data to search.
+    /// - `&[u8]` - The sequence to find.
+    /// - `usize` - The offset to start at.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The absolute position of the first match.
+    #[inline(always)]
+    fn find(data: &[u8], needle: &[u8], offset: usize) -> Option<usize> {
+        data.get(offset..)?
+            .windows(needle.len())
+            .position(|window: &[u8]| window == needle)
+            .map(|index: usize| index + offset)
+    }
+
+    /// Gets a parameter of a header value such as `Content-Type` or `Content-Disposition`.
+    ///
+    /// Parameter names are matched ignoring ASCII case, and quoted values are unescaped.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header value.
+    /// - `&str` - The parameter name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The parameter value if present.
+    pub fn get_header_param(value: &str, param: &str) -> Option<String> {
+        let mut rest: &str = value.split_once(SEMICOLON)?.1;
+        loop {
+            let (key, after_key): (&str, &str) = rest.split_once(EQUAL)?;
+            let key: &str = key.trim();
+            let after_key: &str = after_key.trim_start();
+            let (param_value, next): (String, &str) =
+                if let Some(quoted) = after_key.strip_prefix('"') {
+                    let mut param_value: String = String::new();
+                    let mut chars: std::str::CharIndices<'_> = quoted.char_indices();
+                    let mut end: usize = quoted.len();
+                    while let Some((index, ch)) = chars.next() {
+                        match ch {
+                            '\\' => {
+                                if let Some((_, escaped)) = chars.next() {
+                                    param_value.push(escaped);
+                                }
+                            }
+                            '"' => {
+                                end = index + 1;
+                                break;
+                            }
+                            _ => param_value.push(ch),
+                        }
+                    }
+                    let next: &str = quoted[end..]
+                        .split_once(SEMICOLON)
+                        .map_or(EMPTY_STR, |(_, next)| next);
+                    (param_value, next)
+                } else {
+                    let (param_value, next): (&str, &str) = after_key
+                        .split_once(SEMICOLON)
+                        .unwrap_or((after_key, EMPTY_STR));
+                    (param_value.trim().to_string(), next)
+                };
+            if key.eq_ignore_ascii_case(param) {
+                return Some(param_value);
+            }
+            if next.is_empty() {
+                return None;
+            }
+            rest = next;
+        }
+    }
+
+    /// Gets the boundary of a multipart `Content-Type` header value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The `Content-Type` header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The boundary, or `None` if the type is not multipart or the
+    ///   boundary is missing or invalid.
+    pub fn get_boundary(content_type: &str) -> Option<String> {
+        let media_type: &str = content_type.split(SEMICOLON).next()?.trim();
+        if !media_type.to_ascii_lowercase().starts_with("multipart/") {
+            return None;
+        }
+        Self::get_header_param(content_type, MULTIPART_BOUNDARY)
+            .filter(|boundary: &String| (1..=70).contains(&boundary.len()))
+    }
+
+    /// Gets the default content type of a file from its extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The file name or path.
+    ///
+    /// # Returns
+    ///
+    /// - `&'static str` - The content type, or `application/octet-stream` if the extension
+    ///   is unknown.
+    #[inline(always)]
+    pub fn get_file_content_type(filename: &str) -> &'static str {
+        match FileExtension::parse(FileExtension::get_extension_name(filename)).get_content_type() {
+            EMPTY_STR => APPLICATION_OCTET_STREAM,
+            content_type => content_type,
+        }
+    }
+
+    /// Builds a part from its header section and content.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw header section without the final empty line.
+    /// - `&[u8]` - The part content.
+    /// - `&RequestConfig` - Configuration providing the header limits.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<MultipartPart, RequestError>` - The part, or an error if its headers are invalid.
+    fn parse_part(
+        header_section: &[u8],
+        body: &[u8],
+        config: &RequestConfig,
+    ) -> Result<MultipartPart, RequestError> {
+        let header_section: &str = std::str::from_utf8(header_section)
+            .map_err(|_| RequestError::InvalidMultipart(HttpStatus::BadRequest))?;
+        let mut headers: RequestHeaders = hash_map_xx_hash3_64();
+        let mut header_count: usize = 0;
+        for line in header_section
+            .split(HTTP_BR)
+            .filter(|line: &&str| !line.is_empty())
+        {
+            header_count += 1;
+            Http::check_header_count(header_count, config.get_max_header_count())?;
+            if let Some((key, value)) = Http::parse_header_line(line, config)? {
+                headers.entry(key).or_default().push_back(value);
+            }
+        }
+        let disposition: String = headers
+            .get(CONTENT_DISPOSITION)
+            .and_then(|values: &RequestHeadersValue| values.back().cloned())
+            .unwrap_or_default();
+        let name: Option<String> = Self::get_header_param(&disposition, MULTIPART_NAME);
+        let filename: Option<String> = Self::get_header_param(&disposition, FILENAME);
+        let content_type: String = match headers
+            .get(CONTENT_TYPE)
+            .and_then(|values: &RequestHeadersValue| values.back())
+        {
+            Some(content_type) => content_type.clone(),
+            None => match &filename {
+                Some(filename) => Self::get_file_content_type(filename).to_string(),
+                None => TEXT_PLAIN.to_string(),
+            },
+        };
+        Ok(MultipartPart {
+            headers,
+            name,
+            filename,
+            content_type,
+            body: body.to_vec(),
+        })
+    }
+
+    /// Parses a multipart body.
+    ///
+    /// The preamble before the first boundary and the epilogue after the closing boundary
+    /// are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The body.
+    /// - `&str` - The boundary from the `Content-Type` header.
+    /// - `&RequestConfig` - Configuration providing the header, part size and body size limits.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<MultipartPart>, RequestError>` - The parts, or an error if the body is
+    ///   malformed or exceeds the configured limits.
+    pub fn parse(
+        body: &[u8],
+        boundary: &str,
+        config: &RequestConfig,
+    ) -> Result<Vec<MultipartPart>, RequestError> {
+        let invalid: RequestError = RequestError::InvalidMultipart(HttpStatus::BadRequest);
+        let max_body_size: usize = config.get_max_body_size();
+        if body.len() > max_body_size && max_body_size != DEFAULT_LOW_SECURITY_MAX_BODY_SIZE {
+            return Err(RequestError::ContentLengthTooLarge(
+                HttpStatus::PayloadTooLarge,
+            ));
+        }
+        let max_part_size: usize = config.get_max_multipart_part_size();
+        let mut delimiter: Vec<u8> = Vec::with_capacity(boundary.len() + 4);
+        delimiter.extend_from_slice(HTTP_BR_BYTES);
+        delimiter.extend_from_slice(MULTIPART_DELIMITER_PREFIX.as_bytes());
+        delimiter.extend_from_slice(boundary.as_bytes());
+        let mut position: usize = if body.starts_with(&delimiter[HTTP_BR_BYTES.len()..]) {
+            delimiter.len() - HTTP_BR_BYTES.len()
+        } else {
+            Self::find(body, &delimiter, 0).ok_or(invalid.clone())? + delimiter.len()
+        };
+        let mut parts: Vec<MultipartPart> = Vec::new();
+        loop {
+            if body[position..].starts_with(MULTIPART_DELIMITER_PREFIX.as_bytes()) {
+                return Ok(parts);
+            }
+            while matches!(body.get(position), Some(b' ' | b'\t')) {
+                position += 1;
+            }
+            if !body[position..].starts_with(HTTP_BR_BYTES) {
+                return Err(invalid);
+            }
+            position += HTTP_BR_BYTES.len();
+            let (header_section, content_start): (&[u8], usize) =
+                if body[position..].starts_with(HTTP_BR_BYTES) {
+                    (&[], position + HTTP_BR_BYTES.len())
+                } else {
+                    let header_end: usize =
+                        Self::find(body, HTTP_DOUBLE_BR_BYTES, position).ok_or(invalid.clone())?;
+                    (
+                        &body[position..header_end],
+                        header_end + HTTP_DOUBLE_BR_BYTES.len(),
+                    )
+                };
+            let content_end: usize =
+                Self::find(body, &delimiter, content_start).ok_or(invalid.clone())?;
+            let content: &[u8] = &body[content_start..content_end];
+            if content.len() > max_part_size && max_part_size != DEFAULT_LOW_SECURITY_MAX_BODY_SIZE
+            {
+                return Err(RequestError::MultipartPartTooLarge(
+                    HttpStatus::PayloadTooLarge,
+                ));
+            }
+            parts.push(Self::parse_part(header_section, content, config)?);
+            position = content_end + delimiter.len();
+        }
+    }
+}
+
+impl MultipartSource {
+    /// Opens the file of a file source, positioned at the start of its range.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The file path.
+    /// - `u64` - The first byte of the range.
+    /// - `u64` - The number of bytes in the range.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Take<File>, ResponseError>` - A reader limited to the range, or an error if
+    ///   the file could not be opened.
+    async fn open_file(path: &str, start: u64, length: u64) -> Result<Take<File>, ResponseError> {
+        let mut file: File = File::open(path)
+            .await
+            .map_err(|error: std::io::Error| ResponseError::ReadFile(error.to_string()))?;
+        file.seek(SeekFrom::Start(start))
+            .await
+            .map_err(|error: std::io::Error| ResponseError::ReadFile(error.to_string()))?;
+        Ok(file.take(length))
+    }
+
+    /// Opens the source as a reader over its content.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Take<File>, Option<u64>), ResponseError>` - The reader and the exact number
+    ///   of bytes it must yield for a range, or an error if the file could not be opened or
+    ///   the range is reversed.
+    async fn open(&self) -> Result<(Take<File>, Option<u64>), ResponseError> {
+        match self {
+            Self::File(path) => Ok((Self::open_file(path, 0, u64::MAX).await?, None)),
+            Self::FileRange(path, start, end) => {
+                if end < start {
+                    return Err(ResponseError::ReadFile(format!(
+                        "invalid range {start}-{end} of {path}"
+                    )));
+                }
+                let length: u64 = end - start + 1;
+                Ok((Self::open_file(path, *start, length).await?, Some(length)))
+            }
+            Self::Bytes(_) => Err(ResponseError::ReadFile(format!("{self:?} is not a file"))),
+        }
+    }
+
+    /// Reads the whole content of the source.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, ResponseError>` - The content, or an error if the file could not be
+    ///   read or is shorter than the range.
+    pub async fn read_all(&self) -> Result<Vec<u8>, ResponseError> {
+        if let Self::Bytes(data) = self {
+            return Ok(data.clone());
+        }
+        let (mut reader, length): (Take<File>, Option<u64>) = self.open().await?;
+        let mut data: Vec<u8> = Vec::new();
+        reader
+            .read_to_end(&mut data)
+            .await
+            .map_err(|error: std::io::Error| ResponseError::ReadFile(error.to_string()))?;
+        if length.is_some_and(|length: u64| length != data.len() as u64) {
+            return Err(ResponseError::ReadFile(format!("{self:?} is out of range")));
+        }
+        Ok(data)
+    }
+}
+
+impl MultipartBuilder {
+    /// Creates a builder for a media type with a generated boundary.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The multipart media type.
+    ///
+    /// # Returns
+    ///
+    /// - `MultipartBuilder` - The empty builder.
+    #[inline(always)]
+    fn new(media_type: &str) -> Self {
+        Self {
+            media_type: media_type.to_string(),
+            boundary: Self::generate_boundary(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Creates a builder for a `multipart/form-data` body.
+    ///
+    /// # Returns
+    ///
+    /// - `MultipartBuilder` - The empty builder.
+    #[inline(always)]
+    pub fn form_data() -> Self {
+        Self::new(MULTIPART_FORM_DATA)
+    }
+
+    /// Creates a builder for a `multipart/byteranges` body.
+    ///
+    /// # Returns
+    ///
+    /// - `MultipartBuilder` - The empty builder.
+    #[inline(always)]
+    pub fn byteranges() -> Self {
+        Self::new(MULTIPART_BYTERANGES)
+    }
+
+    /// Generates a random boundary.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The boundary with 128 random bits.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the operating system's random source is unavailable.
+    #[inline(always)]
+    pub fn generate_boundary() -> String {
+        format!(
+            "{MULTIPART_BOUNDARY_PREFIX}{}",
+            hex::encode(random_bytes::<16>())
+        )
+    }
+
+    /// Gets the `Content-Type` header value with the boundary parameter.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The content type.
+    #[inline(always)]
+    pub fn get_content_type(&self) -> String {
+        format!(
+            "{}{SEMICOLON_SPACE}{MULTIPART_BOUNDARY}{EQUAL}{}",
+            self.media_type, self.boundary
+        )
+    }
+
+    /// Checks that a header value or parameter cannot break out of its header line.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok, or `InvalidHeader` if the value contains a CR,
+    ///   LF or NUL character.
+    fn check_header_value(value: &str) -> Result<(), ResponseError> {
+        if !Http::is_valid_header_value(value) {
+            return Err(ResponseError::InvalidHeader(format!("{value:?}")));
+        }
+        Ok(())
+    }
+
+    /// Checks that a header key is a non-empty token.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The key.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok, or `InvalidHeader` if the key is empty or
+    ///   contains a separator, whitespace or control character.
+    fn check_header_key(key: &str) -> Result<(), ResponseError> {
+        if !Http::is_valid_header_name(key) {
+            return Err(ResponseError::InvalidHeader(format!("{key:?}")));
+        }
+        Ok(())
+    }
+
+    /// Quotes a `Content-Disposition` parameter value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The value.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The value in double quotes with quotes and backslashes escaped.
+    #[inline(always)]
+    fn quote(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    /// Builds the `Content-Disposition` header value of a form field.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The field name.
+    /// - `Option<&str>` - The file name.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<String, ResponseError>` - The header value, or `InvalidHeader` if the name
+    ///   or file name contains a CR, LF or NUL character.
+    fn form_disposition(name: &str, filename: Option<&str>) -> Result<String, ResponseError> {
+        Self::check_header_value(name)?;
+        if let Some(filename) = filename {
+            Self::check_header_value(filename)?;
+        }
+        let mut disposition: String = format!(
+            "{FORM_DATA}{SEMICOLON_SPACE}{MULTIPART_NAME}{EQUAL}{}",
+            Self::quote(name)
+        );
+        if let Some(filename) = filename {
+            disposition.push_str(SEMICOLON_SPACE);
+            disposition.push_str(FILENAME);
+            disposition.push_str(EQUAL);
+            disposition.push_str(&Self::quote(filename));
+        }
+        Ok(disposition)
+    }
+
+    /// Adds a part with custom headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<(String, String)>` - The part headers.
+    /// - `MultipartSource` - The part content.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, ResponseError>` - The builder for chaining, or `InvalidHeader`
+    ///   if a header key is not a token or a value contains a CR, LF or NUL character.
+    pub fn part(
+        &mut self,
+        headers: Vec<(String, String)>,
+        source: MultipartSource,
+    ) -> Result<&mut Self, ResponseError> {
+        for (key, value) in &headers {
+            Self::check_header_key(key)?;
+            Self::check_header_value(value)?;
+        }
+        self.parts.push(MultipartBuilderPart { headers, source });
+        Ok(self)
+    }
+
+    /// Adds a text form field.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The field name.
+    /// - `AsRef<str>` - The field value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, ResponseError>` - The builder for chaining, or `InvalidHeader`
+    ///   if a header would contain a CR, LF or NUL character.
+    pub fn text<N, V>(&mut self, name: N, value: V) -> Result<&mut Self, ResponseError>
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let headers: Vec<(String, String)> = vec![(
+            CONTENT_DISPOSITION.to_string(),
+            Self::form_disposition(name.as_ref(), None)?,
+        )];
+        self.part(
+            headers,
+            MultipartSource::Bytes(value.as_ref().as_bytes().to_vec()),
+        )
+    }
+
+    /// Adds a file form field held in memory.
+    ///
+    /// The content type is derived from the file extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The field name.
+    /// - `AsRef<str>` - The file name.
+    /// - `Into<Vec<u8>>` - The file content.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, ResponseError>` - The builder for chaining, or `InvalidHeader`
+    ///   if a header would contain a CR, LF or NUL character.
+    pub fn bytes<N, F, D>(
+        &mut self,
+        name: N,
+        filename: F,
+        data: D,
+    ) -> Result<&mut Self, ResponseError>
+    where
+        N: AsRef<str>,
+        F: AsRef<str>,
+        D: Into<Vec<u8>>,
+    {
+        let filename: &str = filename.as_ref();
+        let headers: Vec<(String, String)> = vec![
+            (
+                CONTENT_DISPOSITION.to_string(),
+                Self::form_disposition(name.as_ref(), Some(filename))?,
+            ),
+            (
+                CONTENT_TYPE.to_string(),
+                Multipart::get_file_content_type(filename).to_string(),
+            ),
+        ];
+        self.part(headers, MultipartSource::Bytes(data.into()))
+    }
+
+    /// Adds a file form field read from disk when the body is built or sent.
+    ///
+    /// The file name and content type are derived from the path.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The field name.
+    /// - `AsRef<str>` - The file path.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, ResponseError>` - The builder for chaining, or `InvalidHeader`
+    ///   if a header would contain a CR, LF or NUL character.
+    pub fn file<N, P>(&mut self, name: N, path: P) -> Result<&mut Self, ResponseError>
+    where
+        N: AsRef<str>,
+        P: AsRef<str>,
+    {
+        let path: &str = path.as_ref();
+        let filename: &str = Path::new(path)
+            .file_name()
+            .and_then(|filename: &OsStr| filename.to_str())
+            .unwrap_or(path);
+        let headers: Vec<(String, String)> = vec![
+            (
+                CONTENT_DISPOSITION.to_string(),
+                Self::form_disposition(name.as_ref(), Some(filename))?,
+            ),
+            (
+                CONTENT_TYPE.to_string(),
+                Multipart::get_file_content_type(filename).to_string(),
+            ),
+        ];
+        self.part(headers, MultipartSource::File(path.to_string()))
+    }
+
+    /// Builds the headers of a byte range part.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The content type of the whole resource.
+    /// - `u64` - The first byte of the range.
+    /// - `u64` - The last byte of the range.
+    /// - `u64` - The size of the whole resource.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<(String, String)>` - The `Content-Type` and `Content-Range` headers.
+    fn range_headers(
+        content_type: &str,
+        start: u64,
+        end: u64,
+        total: u64,
+    ) -> Vec<(String, String)> {
+        vec![
+            (CONTENT_TYPE.to_string(), content_type.to_string()),
+            (
+                CONTENT_RANGE.to_string(),
+                format!("{BYTES}{SPACE}{start}-{end}/{total}"),
+            ),
+        ]
+    }
+
+    /// Adds a byte range held in memory.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The content type of the whole resource.
+    /// - `u64` - The first byte of the range.
+    /// - `u64` - The last byte of the range.
+    /// - `u64` - The size of the whole resource.
+    /// - `Into<Vec<u8>>` - The range content.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, ResponseError>` - The builder for chaining, or `InvalidHeader`
+    ///   if a header would contain a CR, LF or NUL character.
+    pub fn range<C, D>(
+        &mut self,
+        content_type: C,
+        start: u64,
+        end: u64,
+        total: u64,
+        data: D,
+    ) -> Result<&mut Self, ResponseError>
+    where
+        C: AsRef<str>,
+        D: Into<Vec<u8>>,
+    {
+        let headers: Vec<(String, String)> =
+            Self::range_headers(content_type.as_ref(), start, end, total);
+        self.part(headers, MultipartSource::Bytes(data.into()))
+    }
+
+    /// Adds a byte range of a file read from disk when the body is built or sent.
+    ///
+    /// The content type is derived from the file extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The file path.
+    /// - `u64` - The first byte of the range.
+    /// - `u64` - The last byte of the range.
+    /// - `u64` - The size of the whole file.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, ResponseError>` - The builder for chaining, or `InvalidHeader`
+    ///   if a header would contain a CR, LF or NUL character.
+    pub fn file_range<P>(
+        &mut self,
+        path: P,
+        start: u64,
+        end: u64,
+        total: u64,
+    ) -> Result<&mut Self, ResponseError>
+    where
+        P: AsRef<str>,
+    {
+        let path: &str = path.as_ref();
+        let headers: Vec<(String, String)> =
+            Self::range_headers(Multipart::get_file_content_type(path), start, end, total);
+        self.part(
+            headers,
+            MultipartSource::FileRange(path.to_string(), start, end),
+        )
+    }
+
+    /// Builds the delimiter and headers written before a part.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The index of the part.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The bytes preceding the part content.
+    fn build_part_head(&self, index: usize) -> Vec<u8> {
+        let mut head: String = String::new();
+        if index > 0 {
+            head.push_str(HTTP_BR);
+        }
+        head.push_str(MULTIPART_DELIMITER_PREFIX);
+        head.push_str(&self.boundary);
+        head.push_str(HTTP_BR);
+        for (key, value) in &self.parts[index].headers {
+            head.push_str(key);
+            head.push_str(COLON_SPACE);
+            head.push_str(value);
+            head.push_str(HTTP_BR);
+        }
+        head.push_str(HTTP_BR);
+        head.into_bytes()
+    }
+
+    /// Builds the closing delimiter written after the last part.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The closing delimiter bytes.
+    fn build_close(&self) -> Vec<u8> {
+        let prefix: &str = if self.parts.is_empty() {
+            EMPTY_STR
+        } else {
+            HTTP_BR
+        };
+        format!(
+            "{prefix}{MULTIPART_DELIMITER_PREFIX}{}{MULTIPART_DELIMITER_PREFIX}{HTTP_BR}",
+            self.boundary
+        )
+        .into_bytes()
+    }
+
+    /// Builds the whole body in memory.
+    ///
+    /// File parts are read completely, and the boundary is regenerated until no part
+    /// content contains it.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, ResponseError>` - The body, or an error if a file could not be read.
+    pub async fn build(&mut self) -> Result<Vec<u8>, ResponseError> {
+        let mut contents: Vec<Vec<u8>> = Vec::with_capacity(self.parts.len());
+        for part in &self.parts {
+            contents.push(part.source.read_all().await?);
+        }
+        while contents.iter().any(|content: &Vec<u8>| {
+            Multipart::find(content, self.boundary.as_bytes(), 0).is_some()
+        }) {
+            self.boundary = Self::generate_boundary();
+        }
+        let mut body: Vec<u8> = Vec::new();
+        for (index, content) in contents.iter().enumerate() {
+            body.extend_from_slice(&self.build_part_head(index));
+            body.extend_from_slice(content);
+        }
+        body.extend_from_slice(&self.build_close());
+        Ok(body)
+    }
+
+    /// Builds the body into a response and sets its `Content-Type`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Response` - The response to fill.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the body was set, or an error if a file could
+    ///   not be read.
+    pub async fn build_response(&mut self, response: &mut Response) -> Result<(), ResponseError> {
+        let body: Vec<u8> = self.build().await?;
+        response
+            .set_header(CONTENT_TYPE, self.get_content_type())
+            .set_body(body);
+        Ok(())
+    }
+
+    /// Builds the body into an outbound request and sets its `Content-Type`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut RequestBuilder` - The request builder to fill.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the body was set, or an error if a file could
+    ///   not be read.
+    pub async fn build_request(
+        &mut self,
+        builder: &mut RequestBuilder,
+    ) -> Result<(), ResponseError> {
+        let body: Vec<u8> = self.build().await?;
+        builder
+            .header(CONTENT_TYPE, self.get_content_type())
+            .map_err(|error: RequestError| ResponseError::InvalidHeader(error.to_string()))?
+            .body(body);
+        Ok(())
+    }
+
+    /// Sends the body as a chunked response, streaming file parts from disk.
+    ///
+    /// File contents are not scanned for the boundary, which relies on its 128 random bits.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The stream to write to.
+    /// - `&mut Response` - The response whose status line and headers are sent.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the whole body was sent, or an error if a file
+    ///   could not be read or the write failed.
+    pub async fn send<R, W>(
+        &mut self,
+        stream: ArcRwLockStream<R, W>,
+        response: &mut Response,
+    ) -> Result<(), ResponseError>
+    where
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
+    {
+        while self
+            .parts
+            .iter()
+            .any(|part: &MultipartBuilderPart| match &part.source {
+                MultipartSource::Bytes(data) => {
+                    Multipart::find(data, self.boundary.as_bytes(), 0).is_some()
+                }
+                _ => false,
+            })
+        {
+            self.boundary = Self::generate_boundary();
+        }
+        response.set_header(CONTENT_TYPE, self.get_content_type());
+        let mut writer: ChunkedResponseWriter<R, W> =
+            ChunkedResponseWriter::start(stream, response).await?;
+        let mut buffer: Vec<u8> = vec![0; MULTIPART_FILE_BUFFER_SIZE];
+        for (index, part) in self.parts.iter().enumerate() {
+            writer.send_chunk(self.build_part_head(index)).await?;
+            if let MultipartSource::Bytes(data) = &part.source {
+                writer.send_chunk(data).await?;
+                continue;
+            }
+            let (mut reader, length): (Take<File>, Option<u64>) = part.source.open().await?;
+            let mut read_size: u64 = 0;
+            loop {
+                let size: usize = reader
+                    .read(&mut buffer)
+                    .await
+                    .map_err(|error: std::io::Error| ResponseError::ReadFile(error.to_string()))?;
+                if size == 0 {
+                    break;
+                }
+                read_size += size as u64;
+                writer.send_chunk(&buffer[..size]).await?;
+            }
+            if length.is_some_and(|length: u64| length != read_size) {
+                return Err(ResponseError::ReadFile(format!(
+                    "{:?} is out of range",
+                    part.source
+                )));
+            }
+        }
+        writer.send_chunk(self.build_close()).await?;
+        writer.finish().await
+    }
+}
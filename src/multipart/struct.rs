@@ -0,0 +1,40 @@
+use crate::*;
+
+/// A single part of a multipart body.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Getter, PartialEq, Serialize)]
+pub struct MultipartPart {
+    /// The part headers with lowercase keys.
+    pub(super) headers: RequestHeaders,
+    /// The form field name from the `Content-Disposition` header.
+    pub(super) name: Option<String>,
+    /// The file name from the `Content-Disposition` header.
+    pub(super) filename: Option<String>,
+    /// The content type, resolved from the file name when the part does not declare one.
+    pub(super) content_type: String,
+    /// The part content.
+    pub(super) body: Vec<u8>,
+}
+
+/// Parser for multipart bodies.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, New, PartialEq, Serialize)]
+pub struct Multipart;
+
+/// A part added to a `MultipartBuilder`.
+#[derive(Clone, Debug, Deserialize, Eq, Getter, PartialEq, Serialize)]
+pub struct MultipartBuilderPart {
+    /// The part headers in the order they are written.
+    pub(super) headers: Vec<(String, String)>,
+    /// The part content.
+    pub(super) source: MultipartSource,
+}
+
+/// Builder for `multipart/form-data` and `multipart/byteranges` bodies.
+#[derive(Clone, Debug, Deserialize, Eq, Getter, PartialEq, Serialize)]
+pub struct MultipartBuilder {
+    /// The multipart media type without parameters.
+    pub(super) media_type: String,
+    /// The boundary separating the parts.
+    pub(super) boundary: String,
+    /// The parts in the order they are written.
+    pub(super) parts: Vec<MultipartBuilderPart>,
+}
@@ -0,0 +1,204 @@
+use crate::*;
+
+use tokio::io::{DuplexStream, duplex};
+
+const BODY: &[u8] = b"preamble\r\n--XyZ\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\nhello\r\n--XyZ\r\nContent-Disposition: form-data; name=\"file\"; filename=\"a \\\"b\\\".json\"\r\n\r\n{\"k\":1}\r\n--XyZ\r\nContent-Disposition: form-data; name=\"raw\"; filename=\"blob\"\r\nContent-Type: image/png\r\n\r\n\r\n--XyZ--\r\nepilogue";
+
+#[test]
+fn multipart_get_boundary() {
+    assert_eq!(
+        Multipart::get_boundary("multipart/form-data; boundary=abc"),
+        Some("abc".to_string())
+    );
+    assert_eq!(
+        Multipart::get_boundary("Multipart/Form-Data; charset=utf-8; Boundary=\"a;b c\""),
+        Some("a;b c".to_string())
+    );
+    assert_eq!(Multipart::get_boundary("multipart/form-data"), None);
+    assert_eq!(Multipart::get_boundary("text/plain; boundary=abc"), None);
+    assert_eq!(
+        "multipart/form-data; boundary=abc".parse::<ContentType>(),
+        Ok(ContentType::MultipartFormData)
+    );
+}
+
+#[test]
+fn multipart_parse_parts() {
+    let request: Request = RequestBuilder::new()
+        .method(Method::Post)
+        .header(CONTENT_TYPE, "multipart/form-data; boundary=XyZ")
+        .unwrap()
+        .body(BODY)
+        .build();
+    let parts: Vec<MultipartPart> = request
+        .try_get_multipart(&RequestConfig::default())
+        .unwrap();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[0].try_get_name(), &Some("title".to_string()));
+    assert!(!parts[0].is_file());
+    assert_eq!(parts[0].get_content_type(), TEXT_PLAIN);
+    assert_eq!(parts[0].get_body_string(), "hello");
+    assert_eq!(
+        parts[1].try_get_filename(),
+        &Some("a \"b\".json".to_string())
+    );
+    assert_eq!(parts[1].get_content_type(), APPLICATION_JSON);
+    assert_eq!(parts[1].get_body(), b"{\"k\":1}");
+    assert_eq!(parts[2].get_content_type(), "image/png");
+    assert!(parts[2].get_body().is_empty());
+    assert!(
+        Request::default()
+            .try_get_multipart(&RequestConfig::default())
+            .is_err()
+    );
+}
+
+#[test]
+fn multipart_parse_errors_and_limits() {
+    let config: RequestConfig = RequestConfig::default();
+    assert_eq!(
+        Multipart::parse(b"--XyZ\r\n\r\nunterminated", "XyZ", &config),
+        Err(RequestError::InvalidMultipart(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        Multipart::parse(b"no boundary here", "XyZ", &config),
+        Err(RequestError::InvalidMultipart(HttpStatus::BadRequest))
+    );
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_multipart_part_size(4);
+    assert_eq!(
+        Multipart::parse(BODY, "XyZ", &config),
+        Err(RequestError::MultipartPartTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+    config.set_max_multipart_part_size(16).set_max_body_size(16);
+    assert_eq!(
+        Multipart::parse(BODY, "XyZ", &config),
+        Err(RequestError::ContentLengthTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+}
+
+#[tokio::test]
+async fn multipart_builder_form_data_round_trip() {
+    let mut multipart: MultipartBuilder = MultipartBuilder::form_data();
+    multipart
+        .text("title", "hello")
+        .unwrap()
+        .bytes("upload", "say \"hi\".json", b"{}".to_vec())
+        .unwrap();
+    let mut builder: RequestBuilder = RequestBuilder::new();
+    builder.method(Method::Post);
+    multipart.build_request(&mut builder).await.unwrap();
+    let request: Request = builder.build();
+    assert_eq!(
+        request.get_header_back(CONTENT_TYPE),
+        multipart.get_content_type()
+    );
+    let parts: Vec<MultipartPart> = request
+        .try_get_multipart(&RequestConfig::default())
+        .unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].get_body_string(), "hello");
+    assert_eq!(
+        parts[1].try_get_filename(),
+        &Some("say \"hi\".json".to_string())
+    );
+    assert_eq!(parts[1].get_content_type(), APPLICATION_JSON);
+    assert_eq!(parts[1].get_body(), b"{}");
+}
+
+#[tokio::test]
+async fn multipart_builder_avoids_boundary_collision() {
+    let mut multipart: MultipartBuilder = MultipartBuilder::form_data();
+    let boundary: String = multipart.get_boundary().clone();
+    multipart.text("field", format!("--{boundary}--")).unwrap();
+    let body: Vec<u8> = multipart.build().await.unwrap();
+    assert_ne!(multipart.get_boundary(), &boundary);
+    let parts: Vec<MultipartPart> =
+        Multipart::parse(&body, multipart.get_boundary(), &RequestConfig::default()).unwrap();
+    assert_eq!(parts[0].get_body_string(), format!("--{boundary}--"));
+}
+
+#[tokio::test]
+async fn multipart_builder_send_file_byteranges() {
+    let path: std::path::PathBuf = std::env::temp_dir().join(format!(
+        "http_type_multipart_{}.txt",
+        MultipartBuilder::generate_boundary()
+    ));
+    tokio::fs::write(&path, b"0123456789").await.unwrap();
+    let path_str: String = path.to_string_lossy().into_owned();
+    let mut multipart: MultipartBuilder = MultipartBuilder::byteranges();
+    multipart
+        .file_range(&path_str, 0, 2, 10)
+        .unwrap()
+        .file_range(&path_str, 7, 9, 10)
+        .unwrap();
+    let (client, server): (DuplexStream, DuplexStream) = duplex(4096);
+    let client_stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    let server_stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(server);
+    let mut response: Response = Response::default();
+    multipart.send(server_stream, &mut response).await.unwrap();
+    let response: Response =
+        Response::from_stream(&client_stream, &Method::Get, &RequestConfig::default())
+            .await
+            .unwrap();
+    let boundary: String =
+        Multipart::get_boundary(&response.get_header_back(CONTENT_TYPE)).unwrap();
+    let parts: Vec<MultipartPart> =
+        Multipart::parse(response.get_body(), &boundary, &RequestConfig::default()).unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].get_body(), b"012");
+    assert_eq!(parts[0].get_content_type(), TEXT_PLAIN);
+    assert_eq!(parts[1].get_body(), b"789");
+    assert_eq!(
+        parts[1]
+            .get_headers()
+            .get(CONTENT_RANGE)
+            .unwrap()
+            .back()
+            .unwrap(),
+        "bytes 7-9/10"
+    );
+    multipart.file_range(&path_str, 8, 12, 10).unwrap();
+    assert!(multipart.build().await.is_err());
+    tokio::fs::remove_file(&path).await.unwrap();
+}
+
+#[test]
+fn multipart_builder_rejects_header_injection() {
+    let mut multipart: MultipartBuilder = MultipartBuilder::form_data();
+    assert!(matches!(
+        multipart.text("a\r\nX-Injected: 1", "value"),
+        Err(ResponseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        multipart.bytes("upload", "a.txt\r\nX-Injected: 1", b"".to_vec()),
+        Err(ResponseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        multipart.bytes("upload\0", "a.txt", b"".to_vec()),
+        Err(ResponseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        multipart.range("text/plain\nX-Injected: 1", 0, 0, 1, b"a".to_vec()),
+        Err(ResponseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        multipart.part(
+            vec![("X-Bad\r\nX-Injected".to_string(), "1".to_string())],
+            MultipartSource::Bytes(Vec::new()),
+        ),
+        Err(ResponseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        multipart.part(
+            vec![("X-Custom".to_string(), "1\r\n\r\nbody".to_string())],
+            MultipartSource::Bytes(Vec::new()),
+        ),
+        Err(ResponseError::InvalidHeader(_))
+    ));
+    assert!(multipart.parts.is_empty());
+}
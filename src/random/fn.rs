@@ -0,0 +1,18 @@
+/// Fills an array with bytes from the operating system's secure random source.
+///
+/// Used wherever a value must be unpredictable to a peer, such as WebSocket keys,
+/// WebSocket mask keys and multipart boundaries.
+///
+/// # Returns
+///
+/// - `[u8; N]` - The random bytes.
+///
+/// # Panics
+///
+/// This function will panic if the operating system's random source is unavailable.
+#[inline(always)]
+pub(crate) fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes: [u8; N] = [0u8; N];
+    getrandom::fill(&mut bytes).expect("operating system random source is unavailable");
+    bytes
+}
@@ -0,0 +1,5 @@
+mod r#fn;
+#[cfg(test)]
+mod test;
+
+pub(crate) use r#fn::*;
@@ -0,0 +1,12 @@
+use crate::*;
+
+#[test]
+fn random_bytes_and_generated_keys_are_unique() {
+    assert_ne!(random_bytes::<16>(), random_bytes::<16>());
+    let key: String = WebSocketFrame::generate_key();
+    assert_eq!(key.len(), 24);
+    assert_ne!(key, WebSocketFrame::generate_key());
+    let boundary: String = MultipartBuilder::generate_boundary();
+    assert_eq!(boundary.len(), MULTIPART_BOUNDARY_PREFIX.len() + 32);
+    assert_ne!(boundary, MultipartBuilder::generate_boundary());
+}
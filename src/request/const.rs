@@ -0,0 +1,11 @@
+/// The default maximum number of query parameters in a request.
+pub const DEFAULT_MAX_QUERY_COUNT: usize = 100;
+
+/// The maximum number of query parameters in low security mode, which disables the limit.
+pub const DEFAULT_LOW_SECURITY_MAX_QUERY_COUNT: usize = usize::MAX;
+
+/// The maximum number of query parameters in high security mode.
+pub const DEFAULT_HIGH_SECURITY_MAX_QUERY_COUNT: usize = 50;
+
+/// The asterisk-form request target of a server-wide `OPTIONS` request.
+pub const ASTERISK_REQUEST_TARGET: &str = "*";
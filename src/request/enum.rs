@@ -36,6 +36,16 @@ pub enum RequestError {
     PathTooLong(HttpStatus),
     /// Query too long with HTTP status
     QueryTooLong(HttpStatus),
+    /// Too many query parameters with HTTP status
+    TooManyQuerys(HttpStatus),
+    /// Path that does not decode to a valid path with HTTP status
+    InvalidPath(HttpStatus),
+    /// Path that would escape its filesystem root with HTTP status
+    PathTraversal(HttpStatus),
+    /// Request target that does not match its method or form with HTTP status
+    InvalidRequestTarget(HttpStatus),
+    /// Host header that does not match an absolute-form target with HTTP status
+    HostMismatch(HttpStatus),
     /// Header line too long with HTTP status
     HeaderLineTooLong(HttpStatus),
     /// Too many headers with HTTP status
@@ -48,6 +58,34 @@ pub enum RequestError {
     ContentLengthTooLarge(HttpStatus),
     /// Invalid content length with HTTP status
     InvalidContentLength(HttpStatus),
+    /// Invalid chunked transfer encoding with HTTP status
+    InvalidChunkedEncoding(HttpStatus),
+    /// Conflicting Content-Length values with HTTP status
+    ConflictingContentLength(HttpStatus),
+    /// Content-Length sent together with Transfer-Encoding with HTTP status
+    ContentLengthWithTransferEncoding(HttpStatus),
+    /// Transfer-Encoding that does not end with chunked with HTTP status
+    InvalidTransferEncoding(HttpStatus),
+    /// Obsolete header line folding with HTTP status
+    ObsoleteLineFolding(HttpStatus),
+    /// Whitespace between header name and colon with HTTP status
+    WhitespaceBeforeColon(HttpStatus),
+    /// Header line without a colon with HTTP status
+    MissingHeaderColon(HttpStatus),
+    /// Invalid header name with HTTP status
+    InvalidHeaderName(HttpStatus),
+    /// Header value containing a CR, LF or NUL character with HTTP status
+    InvalidHeaderValue(HttpStatus),
+    /// Invalid response status line with HTTP status
+    InvalidStatusLine(HttpStatus),
+    /// Malformed multipart body with HTTP status
+    InvalidMultipart(HttpStatus),
+    /// Multipart body part too large with HTTP status
+    MultipartPartTooLarge(HttpStatus),
+    /// Form body that does not match the target type with HTTP status
+    InvalidFormBody(HttpStatus),
+    /// Query that does not match the target type with HTTP status
+    InvalidQuery(HttpStatus),
     /// Invalid URL scheme with HTTP status
     InvalidUrlScheme(HttpStatus),
     /// Invalid URL host with HTTP status
@@ -84,6 +122,12 @@ pub enum RequestError {
     WebSocketInvalidCloseCode(HttpStatus),
     /// WebSocket invalid extension with HTTP status
     WebSocketInvalidExtension(HttpStatus),
+    /// WebSocket invalid message fragmentation with HTTP status
+    WebSocketInvalidFragmentation(HttpStatus),
+    /// WebSocket frame from a server is masked with HTTP status
+    WebSocketUnexpectedMask(HttpStatus),
+    /// WebSocket opening handshake rejected by the server with HTTP status
+    WebSocketHandshakeFailed(HttpStatus),
     /// HTTP request parts insufficient with HTTP status
     HttpRequestPartsInsufficient(HttpStatus),
     /// TCP stream connection error with HTTP status  
@@ -103,3 +147,17 @@ pub enum RequestError {
     /// Unknown error with HTTP status
     Unknown(HttpStatus),
 }
+
+/// The form of a request target, as defined by RFC 9112 section 3.2.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RequestTarget {
+    /// An absolute path with an optional query, such as `/index.html?a=1`.
+    #[default]
+    Origin,
+    /// An absolute URI sent to a proxy, such as `http://example.com/index.html`.
+    Absolute,
+    /// A `host:port` authority, used only by `CONNECT`.
+    Authority,
+    /// A single `*`, used only by a server-wide `OPTIONS`.
+    Asterisk,
+}
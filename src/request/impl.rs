@@ -122,12 +122,31 @@ impl RequestError {
             Self::RequestTooLong(status) => *status,
             Self::PathTooLong(status) => *status,
             Self::QueryTooLong(status) => *status,
+            Self::TooManyQuerys(status) => *status,
+            Self::InvalidPath(status) => *status,
+            Self::PathTraversal(status) => *status,
+            Self::InvalidRequestTarget(status) => *status,
+            Self::HostMismatch(status) => *status,
             Self::HeaderLineTooLong(status) => *status,
             Self::TooManyHeaders(status) => *status,
             Self::HeaderKeyTooLong(status) => *status,
             Self::HeaderValueTooLong(status) => *status,
             Self::ContentLengthTooLarge(status) => *status,
             Self::InvalidContentLength(status) => *status,
+            Self::InvalidChunkedEncoding(status) => *status,
+            Self::ConflictingContentLength(status) => *status,
+            Self::ContentLengthWithTransferEncoding(status) => *status,
+            Self::InvalidTransferEncoding(status) => *status,
+            Self::ObsoleteLineFolding(status) => *status,
+            Self::WhitespaceBeforeColon(status) => *status,
+            Self::MissingHeaderColon(status) => *status,
+            Self::InvalidHeaderName(status) => *status,
+            Self::InvalidHeaderValue(status) => *status,
+            Self::InvalidStatusLine(status) => *status,
+            Self::InvalidMultipart(status) => *status,
+            Self::MultipartPartTooLarge(status) => *status,
+            Self::InvalidFormBody(status) => *status,
+            Self::InvalidQuery(status) => *status,
             Self::InvalidUrlScheme(status) => *status,
             Self::InvalidUrlHost(status) => *status,
             Self::InvalidUrlPort(status) => *status,
@@ -146,6 +165,9 @@ impl RequestError {
             Self::WebSocketInvalidUtf8(status) => *status,
             Self::WebSocketInvalidCloseCode(status) => *status,
             Self::WebSocketInvalidExtension(status) => *status,
+            Self::WebSocketInvalidFragmentation(status) => *status,
+            Self::WebSocketUnexpectedMask(status) => *status,
+            Self::WebSocketHandshakeFailed(status) => *status,
             Self::HttpRequestPartsInsufficient(status) => *status,
             Self::TcpStreamConnect(status) => *status,
             Self::TlsConnectorBuild(status) => *status,
@@ -190,6 +212,11 @@ impl Default for RequestConfig {
             max_header_value_size: DEFAULT_MAX_HEADER_VALUE_SIZE,
             max_body_size: DEFAULT_MAX_BODY_SIZE,
             read_timeout_ms: DEFAULT_READ_TIMEOUT_MS,
+            lenient_header_parsing: false,
+            max_ws_frame_size: DEFAULT_MAX_BODY_SIZE,
+            max_ws_message_size: DEFAULT_MAX_BODY_SIZE,
+            max_multipart_part_size: DEFAULT_MAX_BODY_SIZE,
+            max_query_count: DEFAULT_MAX_QUERY_COUNT,
         }
     }
 }
@@ -229,6 +256,11 @@ impl RequestConfig {
             max_header_value_size: DEFAULT_LOW_SECURITY_MAX_HEADER_VALUE_SIZE,
             max_body_size: DEFAULT_LOW_SECURITY_MAX_BODY_SIZE,
             read_timeout_ms: DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS,
+            lenient_header_parsing: true,
+            max_ws_frame_size: DEFAULT_LOW_SECURITY_MAX_BODY_SIZE,
+            max_ws_message_size: DEFAULT_LOW_SECURITY_MAX_BODY_SIZE,
+            max_multipart_part_size: DEFAULT_LOW_SECURITY_MAX_BODY_SIZE,
+            max_query_count: DEFAULT_LOW_SECURITY_MAX_QUERY_COUNT,
         }
     }
 
@@ -250,6 +282,11 @@ impl RequestConfig {
             max_header_value_size: DEFAULT_HIGH_SECURITY_MAX_HEADER_VALUE_SIZE,
             max_body_size: DEFAULT_HIGH_SECURITY_MAX_BODY_SIZE,
             read_timeout_ms: DEFAULT_HIGH_SECURITY_READ_TIMEOUT_MS,
+            lenient_header_parsing: false,
+            max_ws_frame_size: DEFAULT_HIGH_SECURITY_MAX_BODY_SIZE,
+            max_ws_message_size: DEFAULT_HIGH_SECURITY_MAX_BODY_SIZE,
+            max_multipart_part_size: DEFAULT_HIGH_SECURITY_MAX_BODY_SIZE,
+            max_query_count: DEFAULT_HIGH_SECURITY_MAX_QUERY_COUNT,
         }
     }
 }
@@ -264,9 +301,13 @@ impl Default for Request {
             method: Method::default(),
             host: String::new(),
             version: HttpVersion::default(),
+            target_form: RequestTarget::default(),
+            scheme: String::new(),
             path: String::new(),
+            raw_query: String::new(),
             querys: hash_map_xx_hash3_64(),
             headers: hash_map_xx_hash3_64(),
+            trailers: hash_map_xx_hash3_64(),
             body: Vec::new(),
         }
     }
@@ -384,9 +425,164 @@ impl Http {
         }
     }
 
+    /// Parses an authority-form request target.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str`: The request target.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestHost>`: The `host:port` authority, or `None` if the target has no
+    ///   valid port, no host, or any character outside an authority.
+    fn parse_authority_target(target: &str) -> Option<RequestHost> {
+        let (host, port): (&str, &str) = target.rsplit_once(COLON)?;
+        let is_valid_host: bool = !host.is_empty()
+            && !host
+                .chars()
+                .any(|ch: char| matches!(ch, '/' | '?' | '#' | '@') || ch.is_whitespace());
+        (is_valid_host && port.parse::<u16>().is_ok()).then(|| target.to_string())
+    }
+
+    /// Removes the default port of a scheme from an authority.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str`: The authority.
+    /// - `&str`: The scheme whose default port is removed.
+    ///
+    /// # Returns
+    ///
+    /// - `&'a str`: The authority without a trailing default port of the scheme.
+    #[inline(always)]
+    fn strip_default_port<'a>(authority: &'a str, scheme: &str) -> &'a str {
+        let default_port: String = format!("{COLON}{}", Protocol::get_port(scheme));
+        authority
+            .strip_suffix(default_port.as_str())
+            .unwrap_or(authority)
+    }
+
+    /// Sets the request host once the headers are parsed.
+    ///
+    /// Origin-form and asterisk-form requests take their host from the `Host` header.
+    /// Authority-form and absolute-form requests keep the host of their target. In strict
+    /// mode an HTTP/1.1 absolute-form request must also carry a `Host` header naming the
+    /// same authority, as RFC 9112 section 3.2 requires of clients.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Request`: The request being parsed.
+    /// - `RequestHost`: The `Host` header value, empty if missing.
+    /// - `&RequestConfig`: Configuration for strictness.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>`: Ok, or `HostMismatch` if the `Host` header contradicts
+    ///   the target.
+    pub(crate) fn apply_host_header(
+        request: &mut Request,
+        host: RequestHost,
+        config: &RequestConfig,
+    ) -> Result<(), RequestError> {
+        match request.target_form {
+            RequestTarget::Origin | RequestTarget::Asterisk => {
+                request.host = host;
+            }
+            RequestTarget::Authority => {}
+            RequestTarget::Absolute => {
+                if config.get_lenient_header_parsing()
+                    || (host.is_empty() && !request.version.is_http1_1_or_higher())
+                {
+                    return Ok(());
+                }
+                if !Self::strip_default_port(&host, &request.scheme)
+                    .eq_ignore_ascii_case(Self::strip_default_port(&request.host, &request.scheme))
+                {
+                    return Err(RequestError::HostMismatch(HttpStatus::BadRequest));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Percent-decodes a request path.
+    ///
+    /// `+` is kept as is, and escapes that are not two hex digits are kept literally.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str`: The raw path.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<String, RequestError>`: The decoded path, or `InvalidPath` if it is not
+    ///   valid UTF-8 or contains a NUL byte.
+    pub(crate) fn decode_path(path: &str) -> Result<String, RequestError> {
+        let bytes: &[u8] = path.as_bytes();
+        let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+        let mut index: usize = 0;
+        while index < bytes.len() {
+            let byte: u8 = bytes[index];
+            if byte == b'%'
+                && let Some(hex) = bytes.get(index + 1..index + 3)
+                && hex.iter().all(u8::is_ascii_hexdigit)
+            {
+                let hex: &str = std::str::from_utf8(hex).unwrap_or_default();
+                decoded.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+                index += 3;
+                continue;
+            }
+            decoded.push(byte);
+            index += 1;
+        }
+        if decoded.contains(&0) {
+            return Err(RequestError::InvalidPath(HttpStatus::BadRequest));
+        }
+        String::from_utf8(decoded).map_err(|_| RequestError::InvalidPath(HttpStatus::BadRequest))
+    }
+
+    /// Removes empty and dot segments from a decoded path as described in RFC 3986.
+    ///
+    /// `..` at the root stays at the root, and a trailing slash is kept.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str`: The decoded path.
+    ///
+    /// # Returns
+    ///
+    /// - `String`: The normalized path, always starting with `/`.
+    pub(crate) fn remove_dot_segments(path: &str) -> String {
+        let mut segments: Vec<&str> = Vec::new();
+        let mut trailing_slash: bool = false;
+        for segment in path.split(DEFAULT_HTTP_PATH) {
+            trailing_slash = true;
+            match segment {
+                EMPTY_STR | "." => {}
+                ".." => {
+                    segments.pop();
+                }
+                segment => {
+                    segments.push(segment);
+                    trailing_slash = false;
+                }
+            }
+        }
+        let mut normalized: String = String::with_capacity(path.len() + 1);
+        for segment in &segments {
+            normalized.push_str(DEFAULT_HTTP_PATH);
+            normalized.push_str(segment);
+        }
+        if trailing_slash || normalized.is_empty() {
+            normalized.push_str(DEFAULT_HTTP_PATH);
+        }
+        normalized
+    }
+
     /// Parses a query string as_ref key-value pairs.
     ///
-    /// Expects format "key1=value1&key2=value2". Empty values are allowed.
+    /// Expects format "key1=value1&key2=value2". Empty values are allowed, keys and values
+    /// are percent-decoded with `+` as a space, and repeated keys keep every value in order.
     ///
     /// # Arguments
     ///
@@ -396,24 +592,48 @@ impl Http {
     ///
     /// - `RequestQuerys` - The parsed query parameters.
     #[inline(always)]
-    fn parse_querys(query: &str) -> RequestQuerys {
+    pub(crate) fn parse_querys(query: &str) -> RequestQuerys {
         let estimated_capacity: usize = query.matches(AND).count() + 1;
         let mut query_map: RequestQuerys = HashMapXxHash3_64::with_capacity_and_hasher(
             estimated_capacity,
             BuildHasherDefault::default(),
         );
-        for pair in query.split(AND) {
-            if let Some((key, value)) = pair.split_once(EQUAL) {
-                if !key.is_empty() {
-                    query_map.insert(key.to_string(), value.to_string());
-                }
-            } else if !pair.is_empty() {
-                query_map.insert(pair.to_string(), String::new());
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            if !key.is_empty() {
+                query_map
+                    .entry(key.into_owned())
+                    .or_default()
+                    .push_back(value.into_owned());
             }
         }
         query_map
     }
 
+    /// Checks if the number of query parameters exceeds the maximum allowed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str`: The raw query string.
+    /// - `usize`: The maximum allowed number of query parameters.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>`: Returns an error if the limit is exceeded and not in low security mode.
+    #[inline(always)]
+    fn check_query_count(query: &str, max_count: usize) -> Result<(), RequestError> {
+        if max_count == DEFAULT_LOW_SECURITY_MAX_QUERY_COUNT {
+            return Ok(());
+        }
+        let count: usize = query
+            .split(AND)
+            .filter(|pair: &&str| !pair.is_empty())
+            .count();
+        if count > max_count {
+            return Err(RequestError::TooManyQuerys(HttpStatus::BadRequest));
+        }
+        Ok(())
+    }
+
     /// Checks if the header count exceeds the maximum allowed.
     ///
     /// # Arguments
@@ -425,7 +645,7 @@ impl Http {
     ///
     /// - `Result<(), RequestError>`: Returns an error if the limit is exceeded and not in low security mode.
     #[inline(always)]
-    fn check_header_count(count: usize, max_count: usize) -> Result<(), RequestError> {
+    pub(crate) fn check_header_count(count: usize, max_count: usize) -> Result<(), RequestError> {
         if count > max_count && max_count != DEFAULT_LOW_SECURITY_MAX_HEADER_COUNT {
             return Err(RequestError::TooManyHeaders(
                 HttpStatus::RequestHeaderFieldsTooLarge,
@@ -445,7 +665,7 @@ impl Http {
     ///
     /// - `Result<(), RequestError>`: Returns an error if the limit is exceeded and not in low security mode.
     #[inline(always)]
-    fn check_header_key_size(key: &str, max_size: usize) -> Result<(), RequestError> {
+    pub(crate) fn check_header_key_size(key: &str, max_size: usize) -> Result<(), RequestError> {
         if key.len() > max_size && max_size != DEFAULT_LOW_SECURITY_MAX_HEADER_KEY_SIZE {
             return Err(RequestError::HeaderKeyTooLong(
                 HttpStatus::RequestHeaderFieldsTooLarge,
@@ -465,7 +685,10 @@ impl Http {
     ///
     /// - `Result<(), RequestError>`: Returns an error if the limit is exceeded and not in low security mode.
     #[inline(always)]
-    fn check_header_value_size(value: &str, max_size: usize) -> Result<(), RequestError> {
+    pub(crate) fn check_header_value_size(
+        value: &str,
+        max_size: usize,
+    ) -> Result<(), RequestError> {
         if value.len() > max_size && max_size != DEFAULT_LOW_SECURITY_MAX_HEADER_VALUE_SIZE {
             return Err(RequestError::HeaderValueTooLong(
                 HttpStatus::RequestHeaderFieldsTooLarge,
@@ -485,7 +708,7 @@ impl Http {
     ///
     /// - `Result<usize, RequestError>`: The parsed content length or an error.
     #[inline(always)]
-    fn check_body_size(value: &str, max_size: usize) -> Result<usize, RequestError> {
+    pub(crate) fn check_body_size(value: &str, max_size: usize) -> Result<usize, RequestError> {
         let length: usize = value.parse::<usize>()?;
         if length > max_size && max_size != DEFAULT_LOW_SECURITY_MAX_BODY_SIZE {
             return Err(RequestError::ContentLengthTooLarge(
@@ -495,798 +718,1871 @@ impl Http {
         Ok(length)
     }
 
-    /// Parses HTTP headers from a buffered reader.
+    /// Checks whether a header line ends the header section.
     ///
-    /// This method reads header lines from the provided buffered reader until an empty line
-    /// is encountered, which indicates the end of headers. It checks header count, length,
-    /// and content according to the provided configuration.
+    /// In lenient mode a line containing only whitespace also ends the section.
     ///
     /// # Arguments
     ///
-    /// - `&mut AsyncBufReadExt + Unpin`: A mutable reference to a buffered reader implementing `AsyncBufReadExt`.
-    /// - `&RequestConfig`: Configuration for security limits and buffer settings.
+    /// - `&str`: The header line without its line ending.
+    /// - `&RequestConfig`: Configuration selecting strict or lenient parsing.
     ///
     /// # Returns
     ///
-    /// - `Result<(RequestHeaders, RequestHost, usize), RequestError>`: A tuple containing:
-    ///   - The parsed headers as a hash map
-    ///   - The host value parsed from the Host header
-    ///   - The content length parsed from the Content-Length header
-    ///   - Or an error if parsing fails
-    async fn parse_headers<R>(
-        reader: &mut R,
-        config: &RequestConfig,
-    ) -> Result<(RequestHeaders, RequestHost, usize), RequestError>
-    where
-        R: AsyncBufReadExt + Unpin,
-    {
-        let buffer_size: usize = config.get_buffer_size();
-        let max_header_count: usize = config.get_max_header_count();
-        let max_header_key_size: usize = config.get_max_header_key_size();
-        let max_header_value_size: usize = config.get_max_header_value_size();
-        let max_body_size: usize = config.get_max_body_size();
-        let mut headers: RequestHeaders =
-            HashMapXxHash3_64::with_capacity_and_hasher(B_16, BuildHasherDefault::default());
-        let mut host: RequestHost = String::new();
-        let mut content_size: usize = 0;
-        let mut header_count: usize = 0;
-        let mut header_line_buffer: String = String::with_capacity(buffer_size);
-        loop {
-            header_line_buffer.clear();
-            AsyncBufReadExt::read_line(reader, &mut header_line_buffer).await?;
-            let header_line: &str = header_line_buffer.trim();
-            if header_line.is_empty() {
-                break;
-            }
-            header_count += 1;
-            Self::check_header_count(header_count, max_header_count)?;
-            let (key_part, value_part): (&str, &str) = match header_line.split_once(COLON) {
-                Some(parts) => parts,
-                None => continue,
-            };
-            let key_trimmed: &str = key_part.trim();
-            if key_trimmed.is_empty() {
-                continue;
-            }
-            let key: String = key_trimmed.to_ascii_lowercase();
-            Self::check_header_key_size(&key, max_header_key_size)?;
-            let value: String = value_part.trim().to_string();
-            Self::check_header_value_size(&value, max_header_value_size)?;
-            match key.as_str() {
-                HOST => host = value.clone(),
-                CONTENT_LENGTH => {
-                    content_size = Self::check_body_size(&value, max_body_size)?;
-                }
-                _ => {}
-            }
-            headers.entry(key).or_default().push_back(value);
+    /// - `bool`: Whether the line is the empty line ending the header section.
+    #[inline(always)]
+    pub(crate) fn is_header_section_end(line: &str, config: &RequestConfig) -> bool {
+        if config.get_lenient_header_parsing() {
+            return line.trim().is_empty();
         }
-        Ok((headers, host, content_size))
+        line.is_empty()
     }
 
-    /// Reads the request body from the buffered reader.
+    /// Checks whether a byte is a valid token character as defined by RFC 9110.
     ///
     /// # Arguments
     ///
-    /// - `&mut BufReader<&mut TcpStream>`: The buffered reader to read from.
-    /// - `usize`: The expected content size.
+    /// - `u8`: The byte to check.
     ///
     /// # Returns
     ///
-    /// - `Result<RequestBody, RequestError>`: The body bytes or an error.
+    /// - `bool`: Whether the byte may appear in a header name.
     #[inline(always)]
-    async fn parse_body(
-        reader: &mut BufReader<&mut TcpStream>,
-        content_size: usize,
-    ) -> Result<RequestBody, RequestError> {
-        let mut body: RequestBody = Vec::with_capacity(content_size);
-        if content_size > 0 {
-            body.resize(content_size, 0);
-            AsyncReadExt::read_exact(reader, &mut body).await?;
-        }
-        Ok(body)
+    fn is_token_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
     }
 
-    /// Parses the HTTP request content from the stream.
+    /// Checks whether a header name is a non-empty token.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str`: The header name.
     ///
-    /// This is an internal helper function that performs the actual parsing.
+    /// # Returns
+    ///
+    /// - `bool`: Whether the name may be written as a header name.
+    #[inline(always)]
+    pub(crate) fn is_valid_header_name(key: &str) -> bool {
+        !key.is_empty() && key.bytes().all(Self::is_token_byte)
+    }
+
+    /// Checks whether a header value stays within its header line.
     ///
     /// # Arguments
     ///
-    /// - `&ArcRwLock<TcpStream>`: The TCP stream to read from.
-    /// - `&RequestConfig`: Configuration for security limits and buffer settings.
+    /// - `&str`: The header value.
     ///
     /// # Returns
     ///
-    /// - `Result<Request, RequestError>`: The parsed request or an error.
-    async fn parse_from_stream(
-        stream: &ArcRwLockStream,
-        config: &RequestConfig,
-    ) -> Result<Request, RequestError> {
-        let buffer_size: usize = config.get_buffer_size();
-        let max_path_size: usize = config.get_max_path_size();
-        let mut buf_stream: RwLockWriteGuard<'_, TcpStream> = stream.write().await;
-        let reader: &mut BufReader<&mut TcpStream> =
-            &mut BufReader::with_capacity(buffer_size, &mut buf_stream);
-        let mut line: String = String::with_capacity(buffer_size);
-        AsyncBufReadExt::read_line(reader, &mut line).await?;
-        let (method, path, version): (RequestMethod, &str, RequestVersion) =
-            Self::parse_first_line(&line)?;
-        Self::check_path_size(path, max_path_size)?;
-        let hash_index: Option<usize> = path.find(HASH);
-        let query_index: Option<usize> = path.find(QUERY);
-        let query_slice: &str = Self::get_query_slice(path, query_index, hash_index);
-        let querys: RequestQuerys = Self::parse_querys(query_slice);
-        let path: RequestPath = Self::parse_path(path, query_index, hash_index);
-        let (headers, host, content_size): (RequestHeaders, RequestHost, usize) =
-            Self::parse_headers(reader, config).await?;
-        let body: RequestBody = Self::parse_body(reader, content_size).await?;
-        Ok(Request {
-            method,
-            host,
-            version,
-            path,
-            querys,
-            headers,
-            body,
-        })
+    /// - `bool`: Whether the value contains no CR, LF or NUL character.
+    #[inline(always)]
+    pub(crate) fn is_valid_header_value(value: &str) -> bool {
+        !value.contains(['\r', '\n', '\0'])
     }
-}
 
-impl Ws {
-    /// Reads data from the stream with optional timeout handling.
+    /// Checks a header before it is written to the wire.
     ///
     /// # Arguments
     ///
-    /// - `&ArcRwLockStream`: The TCP stream to read from.
-    /// - `&mut [u8]`: The buffer to read data into.
-    /// - `Option<Duration>`: The optional timeout duration. If Some, timeout is applied; if None, no timeout.
-    /// - `&mut bool`: Mutable reference to track if we got a client response.
+    /// - `&str`: The header name.
+    /// - `&str`: The header value.
     ///
     /// # Returns
     ///
-    /// - `Result<Option<usize>, RequestError>`: The number of bytes read, None for timeout/ping, or an error.
-    async fn read(
-        stream: &ArcRwLockStream,
-        buffer: &mut [u8],
-        duration_opt: Option<Duration>,
-        is_client_response: &mut bool,
-    ) -> Result<Option<usize>, RequestError> {
-        if let Some(duration) = duration_opt {
-            return match timeout(duration, stream.write().await.read(buffer)).await {
-                Ok(result) => match result {
-                    Ok(len) => Ok(Some(len)),
-                    Err(error) => Err(error.into()),
-                },
-                Err(error) => {
-                    if !*is_client_response {
-                        return Err(error.into());
-                    }
-                    *is_client_response = false;
-                    stream.try_send_body(&PING_FRAME).await?;
-                    Ok(None)
-                }
-            };
+    /// - `Result<(), RequestError>`: Ok, `InvalidHeaderName` if the name is not a token, or
+    ///   `InvalidHeaderValue` if the value contains a CR, LF or NUL character.
+    pub(crate) fn check_header(key: &str, value: &str) -> Result<(), RequestError> {
+        if !Self::is_valid_header_name(key) {
+            return Err(RequestError::InvalidHeaderName(HttpStatus::BadRequest));
         }
-        match stream.write().await.read(buffer).await {
-            Ok(len) => Ok(Some(len)),
-            Err(error) => Err(error.into()),
+        if !Self::is_valid_header_value(value) {
+            return Err(RequestError::InvalidHeaderValue(HttpStatus::BadRequest));
         }
+        Ok(())
     }
 
-    /// Handles a decoded WebSocket Text or Binary frame and accumulates payload data.
+    /// Splits a header line into its name and value following RFC 9112.
+    ///
+    /// Rejects obsolete line folding, lines without a colon, whitespace before the
+    /// colon and names containing characters other than token characters.
     ///
     /// # Arguments
     ///
-    /// - `&Request`: The request to update on completion.
-    /// - `&WebSocketFrame`: The decoded WebSocket frame.
-    /// - `&mut Vec<u8>`: The accumulated frame data.
+    /// - `&str`: The header line without its line ending.
     ///
     /// # Returns
     ///
-    /// - `Result<Option<Request>, RequestError>`: Some(request) if frame is complete, None to continue, or error.
-    #[inline(always)]
-    fn parse_frame(
-        request: &Request,
-        frame: &WebSocketFrame,
-        full_frame: &mut Vec<u8>,
-    ) -> Result<Option<Request>, RequestError> {
-        let payload_data: &[u8] = frame.get_payload_data();
-        full_frame.extend_from_slice(payload_data);
-        if *frame.get_fin() {
-            let mut result: Request = request.clone();
-            result.body = full_frame.clone();
-            return Ok(Some(result));
+    /// - `Result<(&str, &str), RequestError>`: The header name and raw value, or an error.
+    fn split_header_line_strict(line: &str) -> Result<(&str, &str), RequestError> {
+        if line.starts_with([SPACE_U8 as char, TAB_U8 as char]) {
+            return Err(RequestError::ObsoleteLineFolding(HttpStatus::BadRequest));
         }
-        Ok(None)
+        let (key, value): (&str, &str) = line
+            .split_once(COLON)
+            .ok_or(RequestError::MissingHeaderColon(HttpStatus::BadRequest))?;
+        if key.ends_with([SPACE_U8 as char, TAB_U8 as char]) {
+            return Err(RequestError::WhitespaceBeforeColon(HttpStatus::BadRequest));
+        }
+        if !Self::is_valid_header_name(key) {
+            return Err(RequestError::InvalidHeaderName(HttpStatus::BadRequest));
+        }
+        Ok((key, value))
     }
-}
 
-impl Request {
-    /// Parses an HTTP request from a TCP stream.
+    /// Parses a single header line into a lowercase key and a trimmed value.
     ///
-    /// Wraps the stream in a buffered reader and delegates to `http_from_reader`.
-    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied.
+    /// In lenient mode, lines without a colon or with an empty key are skipped and
+    /// whitespace around the key is trimmed. Otherwise the line is validated strictly.
     ///
     /// # Arguments
     ///
-    /// - `&ArcRwLock<TcpStream>` - The TCP stream to read from.
-    /// - `&RequestConfig` - Configuration for security limits and buffer settings.
+    /// - `&str`: The header line without its line ending.
+    /// - `&RequestConfig`: Configuration for security limits and strictness.
     ///
     /// # Returns
     ///
-    /// - `Result<Request, RequestError>` - The parsed request or an error.
-    pub async fn http_from_stream(
-        stream: &ArcRwLockStream,
+    /// - `Result<Option<(RequestHeadersKey, RequestHeadersValueItem)>, RequestError>`: The header
+    ///   key and value, `None` if the line is skipped, or an error if the line is invalid.
+    pub(crate) fn parse_header_line(
+        line: &str,
         config: &RequestConfig,
-    ) -> Result<Request, RequestError> {
-        let timeout_ms: u64 = config.get_read_timeout_ms();
-        if timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
-            return Http::parse_from_stream(stream, config).await;
-        }
-        let duration: Duration = Duration::from_millis(timeout_ms);
-        timeout(duration, Http::parse_from_stream(stream, config)).await?
+    ) -> Result<Option<(RequestHeadersKey, RequestHeadersValueItem)>, RequestError> {
+        let (key_part, value_part): (&str, &str) = if config.get_lenient_header_parsing() {
+            match line.split_once(COLON) {
+                Some((key, value)) if !key.trim().is_empty() => (key.trim(), value),
+                _ => return Ok(None),
+            }
+        } else {
+            Self::split_header_line_strict(line)?
+        };
+        let key: RequestHeadersKey = key_part.to_ascii_lowercase();
+        Self::check_header_key_size(&key, config.get_max_header_key_size())?;
+        let value: RequestHeadersValueItem = value_part.trim().to_string();
+        Self::check_header_value_size(&value, config.get_max_header_value_size())?;
+        Ok(Some((key, value)))
     }
 
-    /// Parses a WebSocket request from a TCP stream.
+    /// Parses all Content-Length values and checks that they agree.
     ///
-    /// Wraps the stream in a buffered reader and delegates to `ws_from_reader`.
-    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied.
+    /// A comma-separated list of identical values is accepted as a single length.
     ///
     /// # Arguments
     ///
-    /// - `&ArcRwLock<TcpStream>`: The TCP stream to read from.
-    /// - `&RequestConfig`: Configuration for security limits and buffer settings.
+    /// - `&RequestHeadersValue`: The Content-Length header values.
+    /// - `usize`: The maximum allowed body size.
     ///
     /// # Returns
     ///
-    /// - `Result<Request, RequestError>`: The parsed WebSocket request or an error.
-    pub async fn ws_from_stream(
-        &self,
-        stream: &ArcRwLockStream,
-        config: &RequestConfig,
-    ) -> Result<Request, RequestError> {
-        let buffer_size: usize = config.get_buffer_size();
-        let read_timeout_ms: u64 = config.get_read_timeout_ms();
-        let mut dynamic_buffer: Vec<u8> = Vec::with_capacity(buffer_size);
-        let mut temp_buffer: Vec<u8> = vec![0; buffer_size];
-        let mut full_frame: Vec<u8> = Vec::new();
-        let mut is_client_response: bool = false;
-        let duration_opt: Option<Duration> =
-            if read_timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
-                None
-            } else {
-                let adjusted_timeout_ms: u64 = (read_timeout_ms >> 1) + (read_timeout_ms & 1);
-                Some(Duration::from_millis(adjusted_timeout_ms))
-            };
-        loop {
-            let len: usize = match Ws::read(
-                stream,
-                &mut temp_buffer,
-                duration_opt,
-                &mut is_client_response,
-            )
-            .await
-            {
-                Ok(Some(len)) => len,
-                Ok(None) => continue,
-                Err(error) => return Err(error),
-            };
-            if len == 0 {
-                return Err(RequestError::IncompleteWebSocketFrame(
+    /// - `Result<usize, RequestError>`: The content length or an error if values are
+    ///   invalid or conflicting.
+    pub(crate) fn parse_content_length_strict(
+        values: &RequestHeadersValue,
+        max_size: usize,
+    ) -> Result<usize, RequestError> {
+        let mut content_size: Option<usize> = None;
+        for item in values.iter().flat_map(|value: &String| value.split(COMMA)) {
+            let item: &str = item.trim();
+            if item.is_empty() || !item.bytes().all(|byte: u8| byte.is_ascii_digit()) {
+                return Err(RequestError::InvalidContentLength(HttpStatus::BadRequest));
+            }
+            let size: usize = Self::check_body_size(item, max_size)?;
+            if content_size.is_some_and(|current: usize| current != size) {
+                return Err(RequestError::ConflictingContentLength(
                     HttpStatus::BadRequest,
                 ));
             }
-            dynamic_buffer.extend_from_slice(&temp_buffer[..len]);
-            while let Some((frame, consumed)) = WebSocketFrame::decode_ws_frame(&dynamic_buffer) {
-                is_client_response = true;
-                dynamic_buffer.drain(0..consumed);
-                match frame.get_opcode() {
-                    WebSocketOpcode::Close => {
-                        return Err(RequestError::ClientClosedConnection(HttpStatus::BadRequest));
-                    }
-                    WebSocketOpcode::Ping | WebSocketOpcode::Pong => continue,
-                    WebSocketOpcode::Text | WebSocketOpcode::Binary => {
-                        match Ws::parse_frame(self, &frame, &mut full_frame) {
-                            Ok(Some(result)) => return Ok(result),
-                            Ok(None) => continue,
-                            Err(error) => return Err(error),
-                        }
-                    }
-                    _ => {
-                        return Err(RequestError::WebSocketOpcodeUnsupported(
-                            HttpStatus::NotImplemented,
-                        ));
-                    }
-                }
-            }
+            content_size = Some(size);
         }
+        Ok(content_size.unwrap_or_default())
     }
 
-    /// Tries to get a query parameter value by key.
+    /// Determines the host and the body framing from the parsed headers.
     ///
-    /// The key type must implement AsRef<str> conversion.
+    /// The last Host header wins. In lenient mode every Content-Length value is
+    /// validated against the maximum body size and the last one determines the content
+    /// length. Otherwise Content-Length values must agree, must not be combined with
+    /// Transfer-Encoding, and Transfer-Encoding must end with chunked.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The query parameter key (implements AsRef<str>).
+    /// - `&RequestHeaders`: The parsed request headers.
+    /// - `&RequestConfig`: Configuration for security limits and strictness.
     ///
     /// # Returns
     ///
-    /// - `Option<RequestQuerysValue>` - The parameter value if exists.
-    #[inline(always)]
-    pub fn try_get_query<K>(&self, key: K) -> Option<RequestQuerysValue>
-    where
-        K: AsRef<str>,
-    {
-        self.querys.get(key.as_ref()).cloned()
+    /// - `Result<(RequestHost, usize, bool), RequestError>`: The host, the content length and
+    ///   whether the body uses chunked transfer encoding, or an error if the framing is invalid.
+    pub(crate) fn parse_header_framing(
+        headers: &RequestHeaders,
+        config: &RequestConfig,
+    ) -> Result<(RequestHost, usize, bool), RequestError> {
+        let host: RequestHost = headers
+            .get(HOST)
+            .and_then(|values: &RequestHeadersValue| values.back().cloned())
+            .unwrap_or_default();
+        let is_chunked: bool = headers
+            .get(TRANSFER_ENCODING)
+            .and_then(|values: &RequestHeadersValue| values.back())
+            .is_some_and(|value: &RequestHeadersValueItem| {
+                Self::is_chunked_transfer_encoding(value)
+            });
+        let max_body_size: usize = config.get_max_body_size();
+        if config.get_lenient_header_parsing() {
+            let mut content_size: usize = 0;
+            if let Some(values) = headers.get(CONTENT_LENGTH) {
+                for value in values {
+                    content_size = Self::check_body_size(value, max_body_size)?;
+                }
+            }
+            return Ok((host, content_size, is_chunked));
+        }
+        let has_transfer_encoding: bool = headers.contains_key(TRANSFER_ENCODING);
+        let content_length_opt: Option<&RequestHeadersValue> = headers.get(CONTENT_LENGTH);
+        if has_transfer_encoding && content_length_opt.is_some() {
+            return Err(RequestError::ContentLengthWithTransferEncoding(
+                HttpStatus::BadRequest,
+            ));
+        }
+        if has_transfer_encoding && !is_chunked {
+            return Err(RequestError::InvalidTransferEncoding(
+                HttpStatus::BadRequest,
+            ));
+        }
+        let content_size: usize = match content_length_opt {
+            Some(values) => Self::parse_content_length_strict(values, max_body_size)?,
+            None => 0,
+        };
+        Ok((host, content_size, is_chunked))
     }
 
-    /// Gets a query parameter value by key.
+    /// Checks whether a Transfer-Encoding header value ends with the chunked coding.
     ///
-    /// The key type must implement AsRef<str> conversion.
+    /// Only the final transfer coding determines the message framing.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The query parameter key (implements AsRef<str>).
+    /// - `&str`: The Transfer-Encoding header value.
     ///
     /// # Returns
     ///
-    /// - `RequestQuerysValue` - The parameter value if exists.
+    /// - `bool`: Whether the final transfer coding is chunked.
+    #[inline(always)]
+    pub(crate) fn is_chunked_transfer_encoding(value: &str) -> bool {
+        value
+            .rsplit(COMMA)
+            .next()
+            .is_some_and(|coding: &str| coding.trim().eq_ignore_ascii_case(CHUNKED))
+    }
+
+    /// Reads the request line and headers from the stream.
+    ///
+    /// # Arguments
     ///
-    /// # Panics
+    /// - `&ArcRwLockStream<R, W>`: The stream to read from.
+    /// - `&RequestConfig`: Configuration for security limits and buffer settings.
     ///
-    /// This function will panic if the query parameter key is not found.
-    #[inline(always)]
-    pub fn get_query<K>(&self, key: K) -> RequestQuerysValue
+    /// # Returns
+    ///
+    /// - `Result<(Request, RequestBodyStream<R, W>), RequestError>`: The request without its
+    ///   body and a reader for the body, or an error.
+    async fn parse_head_from_stream<R, W>(
+        stream: &ArcRwLockStream<R, W>,
+        config: &RequestConfig,
+    ) -> Result<(Request, RequestBodyStream<R, W>), RequestError>
     where
-        K: AsRef<str>,
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
     {
-        self.try_get_query(key).unwrap()
+        let mut parser: RequestParser = RequestParser::new(*config);
+        let mut buffer: Vec<u8> = Vec::with_capacity(config.get_buffer_size());
+        loop {
+            if !buffer.is_empty() {
+                match parser.feed_head(&buffer)? {
+                    RequestHeadParseStatus::Complete(request, framing, consumed) => {
+                        buffer.drain(..consumed);
+                        let body: RequestBodyStream<R, W> =
+                            RequestBodyStream::new(stream.clone(), *config, framing, buffer);
+                        return Ok((*request, body));
+                    }
+                    RequestHeadParseStatus::Partial => buffer.clear(),
+                }
+            }
+            buffer.reserve(config.get_buffer_size());
+            let size: usize = stream.read_mut().await.read_buf(&mut buffer).await?;
+            if size == 0 {
+                return Err(RequestError::ClientClosedConnection(HttpStatus::BadRequest));
+            }
+        }
     }
 
-    /// Tries to retrieve the value of a request header by its key.
+    /// Parses the HTTP request content from the stream.
+    ///
+    /// The request head is parsed first and the body is then read into memory.
+    /// Trailer fields of a chunked body are kept in the request trailers.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// - `&ArcRwLockStream<R, W>`: The stream to read from.
+    /// - `&RequestConfig`: Configuration for security limits and buffer settings.
     ///
     /// # Returns
     ///
-    /// - `Option<RequestHeadersValue>` - The optional header values.
-    #[inline(always)]
-    pub fn try_get_header<K>(&self, key: K) -> Option<RequestHeadersValue>
+    /// - `Result<Request, RequestError>`: The parsed request or an error.
+    async fn parse_from_stream<R, W>(
+        stream: &ArcRwLockStream<R, W>,
+        config: &RequestConfig,
+    ) -> Result<Request, RequestError>
     where
-        K: AsRef<str>,
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
     {
-        self.headers.get(key.as_ref()).cloned()
+        let (mut request, mut body): (Request, RequestBodyStream<R, W>) =
+            Self::parse_head_from_stream(stream, config).await?;
+        request.body = body.read_to_end().await?;
+        request.trailers = body.get_trailers().clone();
+        Ok(request)
     }
 
-    /// Retrieves the value of a request header by its key.
+    /// Parses the request line into a request carrying the method, path, query and version.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// - `&str`: The request line.
+    /// - `&RequestConfig`: Configuration for security limits.
     ///
     /// # Returns
     ///
-    /// - `RequestHeadersValue` - The optional header values.
+    /// - `Result<Request, RequestError>`: The partially filled request or an error.
+    pub(crate) fn parse_request_line(
+        line: &str,
+        config: &RequestConfig,
+    ) -> Result<Request, RequestError> {
+        let (method, path, version): (RequestMethod, &str, RequestVersion) =
+            Self::parse_first_line(line)?;
+        Self::check_path_size(path, config.get_max_path_size())?;
+        let invalid_target: RequestError =
+            RequestError::InvalidRequestTarget(HttpStatus::BadRequest);
+        if method.is_connect() {
+            return Ok(Request {
+                method,
+                version,
+                target_form: RequestTarget::Authority,
+                host: Self::parse_authority_target(path).ok_or(invalid_target)?,
+                ..Default::default()
+            });
+        }
+        if path == ASTERISK_REQUEST_TARGET {
+            if !method.is_options() {
+                return Err(invalid_target);
+            }
+            return Ok(Request {
+                method,
+                version,
+                target_form: RequestTarget::Asterisk,
+                path: path.to_string(),
+                ..Default::default()
+            });
+        }
+        if !path.starts_with(DEFAULT_HTTP_PATH) {
+            let url: HttpUrlComponents =
+                HttpUrlComponents::parse(path).map_err(|_| invalid_target.clone())?;
+            if !(url.protocol.eq_ignore_ascii_case(HTTP_LOWERCASE)
+                || url.protocol.eq_ignore_ascii_case(HTTPS_LOWERCASE))
+                || url.host.as_deref().is_none_or(str::is_empty)
+            {
+                return Err(invalid_target);
+            }
+            Self::check_query_count(
+                url.query.as_deref().unwrap_or_default(),
+                config.get_max_query_count(),
+            )?;
+            let mut request: Request = Request {
+                method,
+                version,
+                target_form: RequestTarget::Absolute,
+                ..Default::default()
+            };
+            request.set_url(&url);
+            return Ok(request);
+        }
+        let hash_index: Option<usize> = path.find(HASH);
+        let query_index: Option<usize> = path.find(QUERY);
+        let query_slice: &str = Self::get_query_slice(path, query_index, hash_index);
+        Self::check_query_count(query_slice, config.get_max_query_count())?;
+        let querys: RequestQuerys = Self::parse_querys(query_slice);
+        let path: RequestPath = Self::parse_path(path, query_index, hash_index);
+        Ok(Request {
+            method,
+            version,
+            path,
+            raw_query: query_slice.to_string(),
+            querys,
+            ..Default::default()
+        })
+    }
+}
+
+impl Ws {
+    /// Reads data from the stream, driving the heartbeat while waiting.
     ///
-    /// # Panics
+    /// # Arguments
     ///
-    /// This function will panic if the header key is not found.
+    /// - `&ArcRwLockStream<R, W>`: The stream to read from.
+    /// - `&mut [u8]`: The buffer to read data into.
+    /// - `&mut WebSocketHeartbeat`: The heartbeat deciding the read timeout and when to ping.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<usize>, RequestError>`: The number of bytes read, None for timeout/ping, or an error.
+    async fn read<R, W>(
+        stream: &ArcRwLockStream<R, W>,
+        buffer: &mut [u8],
+        heartbeat: &mut WebSocketHeartbeat,
+    ) -> Result<Option<usize>, RequestError>
+    where
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
+    {
+        if let Some(duration) = heartbeat.get_timeout() {
+            return match timeout(duration, stream.read_mut().await.read(buffer)).await {
+                Ok(result) => match result {
+                    Ok(len) => Ok(Some(len)),
+                    Err(error) => Err(error.into()),
+                },
+                Err(_) => {
+                    if let Some(payload) = heartbeat.on_timeout()? {
+                        stream
+                            .try_send_body(WebSocketFrame::create_ping_frame(payload, None))
+                            .await?;
+                    }
+                    Ok(None)
+                }
+            };
+        }
+        match stream.read_mut().await.read(buffer).await {
+            Ok(len) => Ok(Some(len)),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Appends a decoded WebSocket data frame to the message being assembled.
+    ///
+    /// Validates the fragmentation sequence and the message size limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `&WebSocketFrame`: The decoded WebSocket data frame.
+    /// - `&mut Vec<u8>`: The accumulated frame data.
+    /// - `&mut Option<WebSocketOpcode>`: The opcode of the message being assembled, if any.
+    /// - `&RequestConfig`: Configuration providing the message size limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<WebSocketOpcode>, RequestError>`: The message opcode if the message is
+    ///   complete, None to continue, or an error.
+    pub(crate) fn append_fragment(
+        frame: &WebSocketFrame,
+        full_frame: &mut Vec<u8>,
+        message_opcode: &mut Option<WebSocketOpcode>,
+        config: &RequestConfig,
+    ) -> Result<Option<WebSocketOpcode>, RequestError> {
+        let opcode: WebSocketOpcode = *frame.get_opcode();
+        match (opcode.is_continuation(), *message_opcode) {
+            (true, None) | (false, Some(_)) => {
+                return Err(RequestError::WebSocketInvalidFragmentation(
+                    HttpStatus::BadRequest,
+                ));
+            }
+            (false, None) => *message_opcode = Some(opcode),
+            (true, Some(_)) => {}
+        }
+        let max_message_size: usize = config.get_max_ws_message_size();
+        let payload_data: &[u8] = frame.get_payload_data();
+        if full_frame.len().saturating_add(payload_data.len()) > max_message_size
+            && max_message_size != DEFAULT_LOW_SECURITY_MAX_BODY_SIZE
+        {
+            return Err(RequestError::WebSocketFrameTooLarge(
+                HttpStatus::PayloadTooLarge,
+            ));
+        }
+        full_frame.extend_from_slice(payload_data);
+        if !*frame.get_fin() {
+            return Ok(None);
+        }
+        Ok(message_opcode.take())
+    }
+
+    /// Checks that the payload of a complete text message is valid UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketOpcode`: The opcode of the complete message.
+    /// - `&[u8]`: The complete, uncompressed message payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>`: Ok if the message is not text or is valid UTF-8.
     #[inline(always)]
-    pub fn get_header<K>(&self, key: K) -> RequestHeadersValue
+    pub(crate) fn check_text_payload(
+        opcode: WebSocketOpcode,
+        payload: &[u8],
+    ) -> Result<(), RequestError> {
+        if opcode.is_text() && std::str::from_utf8(payload).is_err() {
+            return Err(RequestError::WebSocketInvalidUtf8(HttpStatus::BadRequest));
+        }
+        Ok(())
+    }
+
+    /// Handles a decoded WebSocket data frame and accumulates payload data.
+    ///
+    /// Text messages are checked to be valid UTF-8 once complete.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Request`: The request to update on completion.
+    /// - `&WebSocketFrame`: The decoded WebSocket frame.
+    /// - `&mut Vec<u8>`: The accumulated frame data.
+    /// - `&mut Option<WebSocketOpcode>`: The opcode of the message being assembled, if any.
+    /// - `&RequestConfig`: Configuration providing the message size limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Request>, RequestError>`: Some(request) if frame is complete, None to continue, or error.
+    #[inline(always)]
+    fn parse_frame(
+        request: &Request,
+        frame: &WebSocketFrame,
+        full_frame: &mut Vec<u8>,
+        message_opcode: &mut Option<WebSocketOpcode>,
+        config: &RequestConfig,
+    ) -> Result<Option<Request>, RequestError> {
+        let opcode: WebSocketOpcode =
+            match Self::append_fragment(frame, full_frame, message_opcode, config)? {
+                Some(opcode) => opcode,
+                None => return Ok(None),
+            };
+        Self::check_text_payload(opcode, full_frame)?;
+        let mut result: Request = request.clone();
+        result.body = std::mem::take(full_frame);
+        Ok(Some(result))
+    }
+}
+
+impl Request {
+    /// Parses an HTTP request from a stream.
+    ///
+    /// Wraps the stream in a buffered reader and delegates to `http_from_reader`.
+    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream<R, W>` - The stream to read from.
+    /// - `&RequestConfig` - Configuration for security limits and buffer settings.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, RequestError>` - The parsed request or an error.
+    pub async fn http_from_stream<R, W>(
+        stream: &ArcRwLockStream<R, W>,
+        config: &RequestConfig,
+    ) -> Result<Request, RequestError>
     where
-        K: AsRef<str>,
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
     {
-        self.try_get_header(key).unwrap()
+        let timeout_ms: u64 = config.get_read_timeout_ms();
+        if timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
+            return Http::parse_from_stream(stream, config).await;
+        }
+        let duration: Duration = Duration::from_millis(timeout_ms);
+        timeout(duration, Http::parse_from_stream(stream, config)).await?
     }
 
-    /// Tries to retrieve the first value of a request header by its key.
+    /// Parses the line and headers of an HTTP request from a stream, leaving the body unread.
+    ///
+    /// The body is returned as a `RequestBodyStream` that yields it in chunks, so large
+    /// uploads do not have to be buffered in memory. Bytes read past the end of the body
+    /// can be recovered with `RequestBodyStream::take_buffer`.
+    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied to
+    /// reading the head. Each read of the body is bounded by the same timeout.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// - `&ArcRwLockStream<R, W>` - The stream to read from.
+    /// - `&RequestConfig` - Configuration for security limits and buffer settings.
     ///
     /// # Returns
     ///
-    /// - `Option<RequestHeadersValueItem>` - The first header value if exists.
+    /// - `Result<(Request, RequestBodyStream<R, W>), RequestError>` - The request with an
+    ///   empty body and a reader for the body, or an error.
+    pub async fn http_head_from_stream<R, W>(
+        stream: &ArcRwLockStream<R, W>,
+        config: &RequestConfig,
+    ) -> Result<(Request, RequestBodyStream<R, W>), RequestError>
+    where
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
+    {
+        let timeout_ms: u64 = config.get_read_timeout_ms();
+        if timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
+            return Http::parse_head_from_stream(stream, config).await;
+        }
+        let duration: Duration = Duration::from_millis(timeout_ms);
+        timeout(duration, Http::parse_head_from_stream(stream, config)).await?
+    }
+
+    /// Parses a WebSocket request from a stream.
+    ///
+    /// Pings follow `WebSocketConfig::from_read_timeout`: a ping is sent after half the read
+    /// timeout without frames and the pong must arrive within the other half.
+    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied.
+    /// Use `WebSocketReader` for a configurable heartbeat.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream<R, W>`: The stream to read from.
+    /// - `&RequestConfig`: Configuration for security limits and buffer settings.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, RequestError>`: The parsed WebSocket request or an error.
+    pub async fn ws_from_stream<R, W>(
+        &self,
+        stream: &ArcRwLockStream<R, W>,
+        config: &RequestConfig,
+    ) -> Result<Request, RequestError>
+    where
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
+    {
+        let buffer_size: usize = config.get_buffer_size();
+        let mut dynamic_buffer: Vec<u8> = Vec::with_capacity(buffer_size);
+        let mut temp_buffer: Vec<u8> = vec![0; buffer_size];
+        let max_frame_size: usize = config.get_max_ws_frame_size();
+        let mut full_frame: Vec<u8> = Vec::new();
+        let mut message_opcode: Option<WebSocketOpcode> = None;
+        let mut heartbeat: WebSocketHeartbeat = WebSocketHeartbeat::new(
+            WebSocketConfig::from_read_timeout(config.get_read_timeout_ms()),
+        );
+        loop {
+            let len: usize = match Ws::read(stream, &mut temp_buffer, &mut heartbeat).await {
+                Ok(Some(len)) => len,
+                Ok(None) => continue,
+                Err(error) => return Err(error),
+            };
+            if len == 0 {
+                return Err(RequestError::IncompleteWebSocketFrame(
+                    HttpStatus::BadRequest,
+                ));
+            }
+            dynamic_buffer.extend_from_slice(&temp_buffer[..len]);
+            while let Some((frame, consumed)) =
+                WebSocketFrame::try_decode_ws_frame(&dynamic_buffer, max_frame_size)?
+            {
+                heartbeat.on_frame(*frame.get_opcode(), frame.get_payload_data());
+                dynamic_buffer.drain(0..consumed);
+                match frame.get_opcode() {
+                    WebSocketOpcode::Close => {
+                        return Err(RequestError::ClientClosedConnection(HttpStatus::BadRequest));
+                    }
+                    WebSocketOpcode::Ping | WebSocketOpcode::Pong => continue,
+                    WebSocketOpcode::Text
+                    | WebSocketOpcode::Binary
+                    | WebSocketOpcode::Continuation => {
+                        if let Some(result) = Ws::parse_frame(
+                            self,
+                            &frame,
+                            &mut full_frame,
+                            &mut message_opcode,
+                            config,
+                        )? {
+                            return Ok(result);
+                        }
+                    }
+                    WebSocketOpcode::Reserved(_) => {
+                        return Err(RequestError::WebSocketOpcodeUnsupported(
+                            HttpStatus::NotImplemented,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Tries to get the first value of a query parameter by key.
+    ///
+    /// The key type must implement AsRef<str> conversion.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The query parameter key (implements AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestQuerysValueItem>` - The first decoded parameter value if exists.
     #[inline(always)]
-    pub fn try_get_header_front<K>(&self, key: K) -> Option<RequestHeadersValueItem>
+    pub fn try_get_query<K>(&self, key: K) -> Option<RequestQuerysValueItem>
     where
         K: AsRef<str>,
     {
-        self.headers
+        self.querys
             .get(key.as_ref())
-            .and_then(|values| values.front().cloned())
+            .and_then(|values: &RequestQuerysValue| values.front().cloned())
     }
 
-    /// Retrieves the first value of a request header by its key.
+    /// Gets the first value of a query parameter by key.
+    ///
+    /// The key type must implement AsRef<str> conversion.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// - `AsRef<str>` - The query parameter key (implements AsRef<str>).
     ///
     /// # Returns
     ///
-    /// - `RequestHeadersValueItem` - The first header value if exists.
+    /// - `RequestQuerysValueItem` - The first decoded parameter value if exists.
     ///
     /// # Panics
     ///
-    /// This function will panic if the header key is not found.
+    /// This function will panic if the query parameter key is not found.
     #[inline(always)]
-    pub fn get_header_front<K>(&self, key: K) -> RequestHeadersValueItem
+    pub fn get_query<K>(&self, key: K) -> RequestQuerysValueItem
     where
         K: AsRef<str>,
     {
-        self.try_get_header_front(key).unwrap()
+        self.try_get_query(key).unwrap()
     }
 
-    /// Tries to retrieve the last value of a request header by its key.
+    /// Tries to get all values of a query parameter by key.
+    ///
+    /// The key type must implement AsRef<str> conversion.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// - `AsRef<str>` - The query parameter key (implements AsRef<str>).
     ///
     /// # Returns
     ///
-    /// - `Option<RequestHeadersValueItem>` - The last header value if exists.
+    /// - `Option<RequestQuerysValue>` - The decoded parameter values in order if exists.
     #[inline(always)]
-    pub fn try_get_header_back<K>(&self, key: K) -> Option<RequestHeadersValueItem>
+    pub fn try_get_query_values<K>(&self, key: K) -> Option<RequestQuerysValue>
     where
         K: AsRef<str>,
     {
-        self.headers
-            .get(key.as_ref())
-            .and_then(|values| values.back().cloned())
+        self.querys.get(key.as_ref()).cloned()
     }
 
-    /// Retrieves the last value of a request header by its key.
+    /// Gets all values of a query parameter by key.
+    ///
+    /// The key type must implement AsRef<str> conversion.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// - `AsRef<str>` - The query parameter key (implements AsRef<str>).
     ///
     /// # Returns
     ///
-    /// - `RequestHeadersValueItem` - The last header value if exists.
+    /// - `RequestQuerysValue` - The decoded parameter values in order.
     ///
     /// # Panics
     ///
-    /// This function will panic if the header key is not found.
+    /// This function will panic if the query parameter key is not found.
     #[inline(always)]
-    pub fn get_header_back<K>(&self, key: K) -> RequestHeadersValueItem
+    pub fn get_query_values<K>(&self, key: K) -> RequestQuerysValue
     where
         K: AsRef<str>,
     {
-        self.try_get_header_back(key).unwrap()
+        self.try_get_query_values(key).unwrap()
+    }
+
+    /// Sets the raw query string and replaces the query parameters with its decoded pairs.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The raw query string, without the leading `?`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - The request for chaining.
+    #[inline(always)]
+    pub fn set_raw_query<Q>(&mut self, query: Q) -> &mut Self
+    where
+        Q: AsRef<str>,
+    {
+        self.raw_query = query.as_ref().to_string();
+        self.querys = Http::parse_querys(&self.raw_query);
+        self
+    }
+
+    /// Replaces the query parameters and rebuilds the raw query string from them.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestQuerys` - The decoded query parameters.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - The request for chaining.
+    #[inline(always)]
+    pub fn set_querys(&mut self, querys: RequestQuerys) -> &mut Self {
+        self.querys = querys;
+        self.raw_query = self.build_query_string();
+        self
+    }
+
+    /// Tries to retrieve the value of a request header by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestHeadersValue>` - The optional header values.
+    #[inline(always)]
+    pub fn try_get_header<K>(&self, key: K) -> Option<RequestHeadersValue>
+    where
+        K: AsRef<str>,
+    {
+        self.headers.get(key.as_ref()).cloned()
+    }
+
+    /// Retrieves the value of a request header by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestHeadersValue` - The optional header values.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the header key is not found.
+    #[inline(always)]
+    pub fn get_header<K>(&self, key: K) -> RequestHeadersValue
+    where
+        K: AsRef<str>,
+    {
+        self.try_get_header(key).unwrap()
+    }
+
+    /// Tries to retrieve the first value of a request header by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestHeadersValueItem>` - The first header value if exists.
+    #[inline(always)]
+    pub fn try_get_header_front<K>(&self, key: K) -> Option<RequestHeadersValueItem>
+    where
+        K: AsRef<str>,
+    {
+        self.headers
+            .get(key.as_ref())
+            .and_then(|values| values.front().cloned())
+    }
+
+    /// Retrieves the first value of a request header by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestHeadersValueItem` - The first header value if exists.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the header key is not found.
+    #[inline(always)]
+    pub fn get_header_front<K>(&self, key: K) -> RequestHeadersValueItem
+    where
+        K: AsRef<str>,
+    {
+        self.try_get_header_front(key).unwrap()
+    }
+
+    /// Tries to retrieve the last value of a request header by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestHeadersValueItem>` - The last header value if exists.
+    #[inline(always)]
+    pub fn try_get_header_back<K>(&self, key: K) -> Option<RequestHeadersValueItem>
+    where
+        K: AsRef<str>,
+    {
+        self.headers
+            .get(key.as_ref())
+            .and_then(|values| values.back().cloned())
+    }
+
+    /// Retrieves the last value of a request header by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `RequestHeadersValueItem` - The last header value if exists.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the header key is not found.
+    #[inline(always)]
+    pub fn get_header_back<K>(&self, key: K) -> RequestHeadersValueItem
+    where
+        K: AsRef<str>,
+    {
+        self.try_get_header_back(key).unwrap()
+    }
+
+    /// Tries to retrieve the number of values for a specific header.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The count of values for the header if exists.
+    #[inline(always)]
+    pub fn try_get_header_size<K>(&self, key: K) -> Option<usize>
+    where
+        K: AsRef<str>,
+    {
+        self.headers.get(key.as_ref()).map(|values| values.len())
+    }
+
+    /// Retrieves the number of values for a specific header.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The count of values for the header.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the header key is not found.
+    #[inline(always)]
+    pub fn get_header_size<K>(&self, key: K) -> usize
+    where
+        K: AsRef<str>,
+    {
+        self.try_get_header_size(key).unwrap()
+    }
+
+    /// Retrieves the total number of header values across all headers.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The total count of all header values.
+    #[inline(always)]
+    pub fn get_headers_values_size(&self) -> usize {
+        self.headers.values().map(|values| values.len()).sum()
+    }
+
+    /// Retrieves the number of unique headers.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The count of unique header keys.
+    #[inline(always)]
+    pub fn get_headers_size(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Checks if a specific header exists.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header key to check (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the header exists.
+    #[inline(always)]
+    pub fn has_header<K>(&self, key: K) -> bool
+    where
+        K: AsRef<str>,
+    {
+        self.headers.contains_key(key.as_ref())
+    }
+
+    /// Checks if a header contains a specific value.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header key to check (must implement AsRef<str>).
+    /// - `AsRef<str>` - The value to search for (must implement AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the header contains the value.
+    #[inline(always)]
+    pub fn has_header_value<K, V>(&self, key: K, value: V) -> bool
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        if let Some(values) = self.headers.get(key.as_ref()) {
+            values.iter().any(|v| v == value.as_ref())
+        } else {
+            false
+        }
+    }
+
+    /// Tries to parse cookies from the `Cookie` header.
+    ///
+    /// This method retrieves the `Cookie` header value and parses it into
+    /// a collection of key-value pairs representing the cookies.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Cookies>` - The parsed cookies if the header exists, otherwise `None`.
+    #[inline(always)]
+    pub fn try_get_cookies(&self) -> Option<Cookies> {
+        self.try_get_header_back(COOKIE)
+            .map(|cookie_header: String| Cookie::parse(cookie_header))
+    }
+
+    /// Parses cookies from the `Cookie` header.
+    ///
+    /// This method retrieves the `Cookie` header value and parses it into
+    /// a collection of key-value pairs representing the cookies.
+    ///
+    /// # Returns
+    ///
+    /// - `Cookies` - The parsed cookies.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the `Cookie` header is not found.
+    #[inline(always)]
+    pub fn get_cookies(&self) -> Cookies {
+        self.try_get_cookies().unwrap()
+    }
+
+    /// Tries to get a cookie value by its key.
+    ///
+    /// This method first parses the cookies from the `Cookie` header,
+    /// then attempts to retrieve the value for the specified key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The cookie key (implements AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `Option<CookieValue>` - The cookie value if exists.
+    #[inline(always)]
+    pub fn try_get_cookie<K>(&self, key: K) -> Option<CookieValue>
+    where
+        K: AsRef<str>,
+    {
+        self.try_get_cookies()
+            .and_then(|cookies: Cookies| cookies.get(key.as_ref()).cloned())
+    }
+
+    /// Gets a cookie value by its key.
+    ///
+    /// This method first parses the cookies from the `Cookie` header,
+    /// then retrieves the value for the specified key.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The cookie key (implements AsRef<str>).
+    ///
+    /// # Returns
+    ///
+    /// - `CookieValue` - The cookie value.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the `Cookie` header is not found
+    /// or the cookie key does not exist.
+    #[inline(always)]
+    pub fn get_cookie<K>(&self, key: K) -> CookieValue
+    where
+        K: AsRef<str>,
+    {
+        self.try_get_cookie(key).unwrap()
+    }
+
+    /// Retrieves the upgrade type from the request headers.
+    ///
+    /// This method looks for the `UPGRADE` header and attempts to parse its value
+    /// as_ref an `UpgradeType`. If the header is missing or the value is invalid,
+    /// it returns the default `UpgradeType`.
+    ///
+    /// # Returns
+    ///
+    /// - `UpgradeType` - The parsed upgrade type.
+    #[inline(always)]
+    pub fn get_upgrade_type(&self) -> UpgradeType {
+        self.try_get_header_back(UPGRADE)
+            .and_then(|data| data.parse::<UpgradeType>().ok())
+            .unwrap_or_default()
+    }
+
+    /// Retrieves the body content of the request as a UTF-8 encoded string.
+    ///
+    /// This method uses `String::from_utf8_lossy` to convert the byte slice returned by `self.get_body()` as a string.
+    /// If the byte slice contains invalid UTF-8 sequences, they will be replaced with the Unicode replacement character ().
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The body content as a string.
+    #[inline(always)]
+    pub fn get_body_string(&self) -> String {
+        String::from_utf8_lossy(self.get_body()).into_owned()
+    }
+
+    /// Deserializes the body content of the request as_ref a specified type `T`.
+    ///
+    /// This method first retrieves the body content as a byte slice using `self.get_body()`.
+    /// It then attempts to deserialize the byte slice as_ref the specified type `T` using `json_from_slice`.
+    ///
+    /// # Arguments
+    ///
+    /// - `DeserializeOwned` - The target type to deserialize as_ref (must implement DeserializeOwned).
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, serde_json::Error>` - The deserialization result.
+    #[inline(always)]
+    pub fn try_get_body_json<T>(&self) -> Result<T, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        serde_json::from_slice(self.get_body())
+    }
+
+    /// Deserializes the body content of the request as_ref a specified type `T`.
+    ///
+    /// This method first retrieves the body content as a byte slice using `self.get_body()`.
+    /// It then attempts to deserialize the byte slice as_ref the specified type `T` using `json_from_slice`.
+    ///
+    /// # Arguments
+    ///
+    /// - `DeserializeOwned` - The target type to deserialize as_ref (must implement DeserializeOwned).
+    ///
+    /// # Returns
+    ///
+    /// - `T` - The deserialized body content.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the deserialization fails.
+    #[inline(always)]
+    pub fn get_body_json<T>(&self) -> T
+    where
+        T: DeserializeOwned,
+    {
+        self.try_get_body_json().unwrap()
+    }
+
+    /// Deserializes the `application/x-www-form-urlencoded` body as_ref a specified type `T`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The deserialized form, or `InvalidFormBody` with
+    ///   `HttpStatus::BadRequest` if the body does not match `T`.
+    #[inline(always)]
+    pub fn try_get_body_form<T>(&self) -> Result<T, RequestError>
+    where
+        T: DeserializeOwned,
+    {
+        serde_urlencoded::from_bytes(self.get_body())
+            .map_err(|_| RequestError::InvalidFormBody(HttpStatus::BadRequest))
+    }
+
+    /// Deserializes the `application/x-www-form-urlencoded` body as_ref a specified type `T`.
+    ///
+    /// # Returns
+    ///
+    /// - `T` - The deserialized form.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the deserialization fails.
+    #[inline(always)]
+    pub fn get_body_form<T>(&self) -> T
+    where
+        T: DeserializeOwned,
+    {
+        self.try_get_body_form().unwrap()
+    }
+
+    /// Deserializes the query parameters as_ref a specified type `T`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The deserialized query, or `InvalidQuery` with
+    ///   `HttpStatus::BadRequest` if the query does not match `T`.
+    #[inline(always)]
+    pub fn try_get_querys_as<T>(&self) -> Result<T, RequestError>
+    where
+        T: DeserializeOwned,
+    {
+        serde_urlencoded::from_str(&self.get_query_string())
+            .map_err(|_| RequestError::InvalidQuery(HttpStatus::BadRequest))
+    }
+
+    /// Deserializes the query parameters as_ref a specified type `T`.
+    ///
+    /// # Returns
+    ///
+    /// - `T` - The deserialized query.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the deserialization fails.
+    #[inline(always)]
+    pub fn get_querys_as<T>(&self) -> T
+    where
+        T: DeserializeOwned,
+    {
+        self.try_get_querys_as().unwrap()
+    }
+
+    /// Checks whether the WebSocket upgrade is enabled for this request.
+    ///
+    /// This method determines if the `UPGRADE` header indicates a WebSocket connection.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether WebSocket upgrade is enabled.
+    #[inline(always)]
+    pub fn is_ws_upgrade_type(&self) -> bool {
+        self.get_upgrade_type().is_ws()
+    }
+
+    /// Retrieves the WebSocket subprotocols offered by the client.
+    ///
+    /// All `Sec-WebSocket-Protocol` headers are split on commas, in the order sent.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The offered subprotocols, empty if none were offered.
+    pub fn get_ws_protocols(&self) -> Vec<String> {
+        self.try_get_header(SEC_WEBSOCKET_PROTOCOL)
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|value: &String| value.split(COMMA))
+            .map(str::trim)
+            .filter(|protocol: &&str| !protocol.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Checks if the current upgrade type is HTTP/2 cleartext (h2c).
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the upgrade type is h2c.
+    #[inline(always)]
+    pub fn is_h2c_upgrade_type(&self) -> bool {
+        self.get_upgrade_type().is_h2c()
+    }
+
+    /// Checks if the current upgrade type is TLS (any version).
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the upgrade type is TLS.
+    #[inline(always)]
+    pub fn is_tls_upgrade_type(&self) -> bool {
+        self.get_upgrade_type().is_tls()
+    }
+
+    /// Checks whether the upgrade type is unknown.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the upgrade type is unknown.
+    #[inline(always)]
+    pub fn is_unknown_upgrade_type(&self) -> bool {
+        self.get_upgrade_type().is_unknown()
+    }
+
+    /// Determines if a keep-alive connection should be enabled for this request.
+    ///
+    /// This function checks the `Connection` header and the HTTP version to determine
+    /// if keep-alive should be enabled. The logic is as follows:
+    ///
+    /// 1. If the `Connection` header exists:
+    ///    - Returns `true` if the header value is "keep-alive" (case-insensitive).
+    ///    - Returns `false` if the header value is "close" (case-insensitive).
+    /// 2. If no `Connection` header is present:
+    ///    - Returns `true` if the HTTP version is 1.1 or higher.
+    ///    - Returns `false` otherwise.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether keep-alive should be enabled.
+    #[inline(always)]
+    pub fn is_enable_keep_alive(&self) -> bool {
+        if let Some(connection_value) = self.try_get_header_back(CONNECTION) {
+            if connection_value.eq_ignore_ascii_case(KEEP_ALIVE) {
+                return true;
+            } else if connection_value.eq_ignore_ascii_case(CLOSE) {
+                return self.is_ws_upgrade_type();
+            }
+        }
+        self.get_version().is_http1_1_or_higher() || self.is_ws_upgrade_type()
+    }
+
+    /// Gets the percent-decoded path with empty and dot segments removed.
+    ///
+    /// The raw path stays available through `get_path`, for example to verify signatures.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<RequestPath, RequestError>` - The normalized path, or `InvalidPath` if it
+    ///   does not decode to UTF-8 or contains a NUL byte.
+    #[inline(always)]
+    pub fn try_get_normalized_path(&self) -> Result<RequestPath, RequestError> {
+        Http::decode_path(&self.path).map(|path: String| Http::remove_dot_segments(&path))
+    }
+
+    /// Gets the percent-decoded path with empty and dot segments removed.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestPath` - The normalized path.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the path does not decode to UTF-8 or contains a NUL byte.
+    #[inline(always)]
+    pub fn get_normalized_path(&self) -> RequestPath {
+        self.try_get_normalized_path().unwrap()
+    }
+
+    /// Joins the decoded path onto a filesystem root.
+    ///
+    /// Unlike `try_get_normalized_path`, a `..` that would leave the root is refused
+    /// instead of being clamped, as is any segment holding a backslash or anything other
+    /// than a plain file name.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<Path>` - The filesystem root.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<PathBuf, RequestError>` - The joined path, `InvalidPath` if the path does
+    ///   not decode, or `PathTraversal` if it would escape the root.
+    pub fn try_join_path<P>(&self, root: P) -> Result<PathBuf, RequestError>
+    where
+        P: AsRef<Path>,
+    {
+        let traversal: RequestError = RequestError::PathTraversal(HttpStatus::Forbidden);
+        let path: String = Http::decode_path(&self.path)?;
+        if path.contains('\\') {
+            return Err(traversal);
+        }
+        let mut segments: Vec<&str> = Vec::new();
+        for segment in path.split(DEFAULT_HTTP_PATH) {
+            match segment {
+                EMPTY_STR | "." => {}
+                ".." => {
+                    segments.pop().ok_or(traversal.clone())?;
+                }
+                segment => {
+                    let mut components: Components<'_> = Path::new(segment).components();
+                    if !matches!(
+                        (components.next(), components.next()),
+                        (Some(Component::Normal(_)), None)
+                    ) {
+                        return Err(traversal);
+                    }
+                    segments.push(segment);
+                }
+            }
+        }
+        let mut joined: PathBuf = root.as_ref().to_path_buf();
+        joined.extend(segments);
+        Ok(joined)
     }
 
-    /// Tries to retrieve the number of values for a specific header.
-    ///
-    /// # Arguments
-    ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// Gets the id of the last Server-Sent Event a reconnecting client received.
     ///
     /// # Returns
     ///
-    /// - `Option<usize>` - The count of values for the header if exists.
+    /// - `Option<String>` - The `Last-Event-ID` header value, or `None` if it is missing or
+    ///   empty.
     #[inline(always)]
-    pub fn try_get_header_size<K>(&self, key: K) -> Option<usize>
-    where
-        K: AsRef<str>,
-    {
-        self.headers.get(key.as_ref()).map(|values| values.len())
+    pub fn try_get_last_event_id(&self) -> Option<String> {
+        self.try_get_header_back(LAST_EVENT_ID)
+            .filter(|id: &String| !id.is_empty())
     }
 
-    /// Retrieves the number of values for a specific header.
+    /// Parses the body as a multipart body using the boundary from the `Content-Type` header.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header's key (must implement AsRef<str>).
+    /// - `&RequestConfig` - Configuration providing the header, part size and body size limits.
     ///
     /// # Returns
     ///
-    /// - `usize` - The count of values for the header.
+    /// - `Result<Vec<MultipartPart>, RequestError>` - The parts, or an error if the request is
+    ///   not multipart, has no boundary, is malformed or exceeds the configured limits.
+    pub fn try_get_multipart(
+        &self,
+        config: &RequestConfig,
+    ) -> Result<Vec<MultipartPart>, RequestError> {
+        let boundary: String = self
+            .try_get_header_back(CONTENT_TYPE)
+            .and_then(|content_type: String| Multipart::get_boundary(&content_type))
+            .ok_or(RequestError::InvalidMultipart(HttpStatus::BadRequest))?;
+        Multipart::parse(self.get_body(), &boundary, config)
+    }
+
+    /// Determines if keep-alive should be disabled for this request.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// This function will panic if the header key is not found.
+    /// - `bool` - Whether keep-alive should be disabled.
     #[inline(always)]
-    pub fn get_header_size<K>(&self, key: K) -> usize
-    where
-        K: AsRef<str>,
-    {
-        self.try_get_header_size(key).unwrap()
+    pub fn is_disable_keep_alive(&self) -> bool {
+        !self.is_enable_keep_alive()
     }
+}
 
-    /// Retrieves the total number of header values across all headers.
+impl Request {
+    /// Builds the query string from the query parameters, without the leading `?`.
+    ///
+    /// Query parameters are emitted sorted by key so the result is deterministic, repeated
+    /// keys keep their value order, and keys and values are percent-encoded.
     ///
     /// # Returns
     ///
-    /// - `usize` - The total count of all header values.
-    #[inline(always)]
-    pub fn get_headers_values_size(&self) -> usize {
-        self.headers.values().map(|values| values.len()).sum()
+    /// - `String` - The query string, empty if there are no query parameters.
+    fn build_query_string(&self) -> String {
+        let mut querys: Vec<(&RequestQuerysKey, &RequestQuerysValue)> =
+            self.querys.iter().collect();
+        querys.sort_by_key(|(key, _)| *key);
+        let mut query: String = String::new();
+        for (key, values) in querys {
+            for value in values {
+                if !query.is_empty() {
+                    query.push_str(AND);
+                }
+                query.extend(form_urlencoded::byte_serialize(key.as_bytes()));
+                if !value.is_empty() {
+                    query.push_str(EQUAL);
+                    query.extend(form_urlencoded::byte_serialize(value.as_bytes()));
+                }
+            }
+        }
+        query
     }
 
-    /// Retrieves the number of unique headers.
+    /// Gets the query string sent on the wire, without the leading `?`.
+    ///
+    /// The raw query is emitted verbatim while it still decodes to the query parameters.
+    /// Once the parameters have been changed through `get_mut_querys`, the query string is
+    /// rebuilt from them instead.
     ///
     /// # Returns
     ///
-    /// - `usize` - The count of unique header keys.
-    #[inline(always)]
-    pub fn get_headers_size(&self) -> usize {
-        self.headers.len()
+    /// - `String` - The query string, empty if there are no query parameters.
+    fn get_query_string(&self) -> String {
+        if Http::parse_querys(&self.raw_query) == self.querys {
+            return self.raw_query.clone();
+        }
+        self.build_query_string()
     }
 
-    /// Checks if a specific header exists.
-    ///
-    /// # Arguments
+    /// Gets the request target, the path followed by the query string.
     ///
-    /// - `AsRef<str>` - The header key to check (must implement AsRef<str>).
+    /// The query string is emitted as received or set, without re-ordering or re-encoding.
+    /// Authority-form and asterisk-form requests keep their form, while absolute-form
+    /// requests are emitted in origin-form, as a proxy forwards them.
     ///
     /// # Returns
     ///
-    /// - `bool` - Whether the header exists.
-    #[inline(always)]
-    pub fn has_header<K>(&self, key: K) -> bool
-    where
-        K: AsRef<str>,
-    {
-        self.headers.contains_key(key.as_ref())
+    /// - `String` - The request target.
+    pub fn get_target(&self) -> String {
+        match self.target_form {
+            RequestTarget::Authority => return self.host.clone(),
+            RequestTarget::Asterisk => return ASTERISK_REQUEST_TARGET.to_string(),
+            RequestTarget::Origin | RequestTarget::Absolute => {}
+        }
+        let path: &str = if self.path.is_empty() {
+            DEFAULT_HTTP_PATH
+        } else {
+            &self.path
+        };
+        let query: String = self.get_query_string();
+        if query.is_empty() {
+            return path.to_string();
+        }
+        let mut target: String = String::with_capacity(DEFAULT_BUFFER_SIZE);
+        target.push_str(path);
+        target.push_str(QUERY);
+        target.push_str(&query);
+        target
     }
 
-    /// Checks if a header contains a specific value.
+    /// Points the request at a URL.
+    ///
+    /// Sets the host, path and query from the URL components. The port is kept in the
+    /// host when it is not the default port of the scheme, and an existing `Host` header
+    /// is replaced.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The header key to check (must implement AsRef<str>).
-    /// - `AsRef<str>` - The value to search for (must implement AsRef<str>).
+    /// - `&HttpUrlComponents` - The parsed URL.
     ///
     /// # Returns
     ///
-    /// - `bool` - Whether the header contains the value.
-    #[inline(always)]
-    pub fn has_header_value<K, V>(&self, key: K, value: V) -> bool
-    where
-        K: AsRef<str>,
-        V: AsRef<str>,
-    {
-        if let Some(values) = self.headers.get(key.as_ref()) {
-            values.iter().any(|v| v == value.as_ref())
-        } else {
-            false
+    /// - `&mut Self` - The request for chaining.
+    pub fn set_url(&mut self, url: &HttpUrlComponents) -> &mut Self {
+        let mut host: RequestHost = url.host.clone().unwrap_or_default();
+        if let Some(port) = url.port {
+            host.push_str(COLON);
+            host.push_str(&port.to_string());
+        }
+        if self.has_header(HOST) {
+            let mut values: RequestHeadersValue = VecDeque::with_capacity(1);
+            values.push_back(host.clone());
+            self.headers.insert(HOST.to_string(), values);
         }
+        self.host = host;
+        self.scheme = url.protocol.to_ascii_lowercase();
+        self.path = match url.path.as_deref() {
+            Some(path) if !path.is_empty() => path.to_string(),
+            _ => DEFAULT_HTTP_PATH.to_string(),
+        };
+        self.set_raw_query(url.query.as_deref().unwrap_or_default());
+        self
     }
 
-    /// Tries to parse cookies from the `Cookie` header.
-    ///
-    /// This method retrieves the `Cookie` header value and parses it into
-    /// a collection of key-value pairs representing the cookies.
+    /// Checks that the host and every header can be written to the wire unchanged.
     ///
     /// # Returns
     ///
-    /// - `Option<Cookies>` - The parsed cookies if the header exists, otherwise `None`.
-    #[inline(always)]
-    pub fn try_get_cookies(&self) -> Option<Cookies> {
-        self.try_get_header_back(COOKIE)
-            .map(|cookie_header: String| Cookie::parse(cookie_header))
+    /// - `Result<(), RequestError>` - Ok, `InvalidHeaderName` if a header name is not a
+    ///   token, or `InvalidHeaderValue` if the host or a header value contains a CR, LF or
+    ///   NUL character.
+    pub fn check_headers(&self) -> Result<(), RequestError> {
+        Http::check_header(HOST, &self.host)?;
+        for (key, values) in self.headers.iter() {
+            for value in values.iter() {
+                Http::check_header(key, value)?;
+            }
+        }
+        Ok(())
     }
 
-    /// Parses cookies from the `Cookie` header.
+    /// Builds the full HTTP request as a byte vector.
     ///
-    /// This method retrieves the `Cookie` header value and parses it into
-    /// a collection of key-value pairs representing the cookies.
+    /// Headers are written as set; call `check_headers` first for headers that come
+    /// from untrusted input.
+    ///
+    /// A `Host` header is emitted from the request host if none is set, and a
+    /// `Content-Length` header is emitted for a non-empty body unless a length or
+    /// transfer encoding is already set.
     ///
     /// # Returns
     ///
-    /// - `Cookies` - The parsed cookies.
+    /// - `RequestData` - The complete HTTP/1.1 request bytes.
+    pub fn build(&self) -> RequestData {
+        let mut request_string: String = String::with_capacity(DEFAULT_BUFFER_SIZE);
+        request_string.push_str(&self.method.to_string());
+        request_string.push_str(SPACE);
+        request_string.push_str(&self.get_target());
+        request_string.push_str(SPACE);
+        request_string.push_str(&self.version.to_string());
+        request_string.push_str(HTTP_BR);
+        let mut push_header = |key: &str, value: &str| {
+            request_string.push_str(key);
+            request_string.push_str(COLON_SPACE);
+            request_string.push_str(value);
+            request_string.push_str(HTTP_BR);
+        };
+        if !self.host.is_empty() && !self.has_header(HOST) {
+            push_header(HOST, &self.host);
+        }
+        for (key, values) in self.headers.iter() {
+            for value in values.iter() {
+                push_header(key, value);
+            }
+        }
+        if !self.body.is_empty()
+            && !self.has_header(CONTENT_LENGTH)
+            && !self.has_header(TRANSFER_ENCODING)
+        {
+            push_header(CONTENT_LENGTH, &self.body.len().to_string());
+        }
+        request_string.push_str(HTTP_BR);
+        let mut request_bytes: RequestData = request_string.into_bytes();
+        request_bytes.extend_from_slice(&self.body);
+        request_bytes
+    }
+}
+
+impl Default for RequestBuilder {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            request: Request {
+                method: Method::Get,
+                path: DEFAULT_HTTP_PATH.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl RequestBuilder {
+    /// Creates a new `RequestBuilder` for a `GET /` HTTP/1.1 request.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// This function will panic if the `Cookie` header is not found.
+    /// - `RequestBuilder` - An empty builder.
     #[inline(always)]
-    pub fn get_cookies(&self) -> Cookies {
-        self.try_get_cookies().unwrap()
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Tries to get a cookie value by its key.
-    ///
-    /// This method first parses the cookies from the `Cookie` header,
-    /// then attempts to retrieve the value for the specified key.
+    /// Sets the request method.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The cookie key (implements AsRef<str>).
+    /// - `RequestMethod` - The request method.
     ///
     /// # Returns
     ///
-    /// - `Option<CookieValue>` - The cookie value if exists.
+    /// - `&mut Self` - The builder for chaining.
     #[inline(always)]
-    pub fn try_get_cookie<K>(&self, key: K) -> Option<CookieValue>
-    where
-        K: AsRef<str>,
-    {
-        self.try_get_cookies()
-            .and_then(|cookies: Cookies| cookies.get(key.as_ref()).cloned())
+    pub fn method(&mut self, method: RequestMethod) -> &mut Self {
+        self.request.method = method;
+        self
     }
 
-    /// Gets a cookie value by its key.
-    ///
-    /// This method first parses the cookies from the `Cookie` header,
-    /// then retrieves the value for the specified key.
+    /// Sets the HTTP version.
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The cookie key (implements AsRef<str>).
+    /// - `RequestVersion` - The HTTP version.
     ///
     /// # Returns
     ///
-    /// - `CookieValue` - The cookie value.
-    ///
-    /// # Panics
-    ///
-    /// This function will panic if the `Cookie` header is not found
-    /// or the cookie key does not exist.
+    /// - `&mut Self` - The builder for chaining.
     #[inline(always)]
-    pub fn get_cookie<K>(&self, key: K) -> CookieValue
-    where
-        K: AsRef<str>,
-    {
-        self.try_get_cookie(key).unwrap()
+    pub fn version(&mut self, version: RequestVersion) -> &mut Self {
+        self.request.version = version;
+        self
     }
 
-    /// Retrieves the upgrade type from the request headers.
+    /// Sets the host, path and query from URL components.
     ///
-    /// This method looks for the `UPGRADE` header and attempts to parse its value
-    /// as_ref an `UpgradeType`. If the header is missing or the value is invalid,
-    /// it returns the default `UpgradeType`.
+    /// The port is kept in the host when it is not the default port of the scheme.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpUrlComponents` - The parsed URL.
     ///
     /// # Returns
     ///
-    /// - `UpgradeType` - The parsed upgrade type.
+    /// - `&mut Self` - The builder for chaining.
     #[inline(always)]
-    pub fn get_upgrade_type(&self) -> UpgradeType {
-        self.try_get_header_back(UPGRADE)
-            .and_then(|data| data.parse::<UpgradeType>().ok())
-            .unwrap_or_default()
+    pub fn url(&mut self, url: &HttpUrlComponents) -> &mut Self {
+        self.request.set_url(url);
+        self
     }
 
-    /// Retrieves the body content of the request as a UTF-8 encoded string.
+    /// Sets a header without checking it, replacing any existing values.
     ///
-    /// This method uses `String::from_utf8_lossy` to convert the byte slice returned by `self.get_body()` as a string.
-    /// If the byte slice contains invalid UTF-8 sequences, they will be replaced with the Unicode replacement character ().
+    /// # Arguments
+    ///
+    /// - `&str` - The lowercase header key.
+    /// - `String` - The header value.
     ///
     /// # Returns
     ///
-    /// - `String` - The body content as a string.
-    #[inline(always)]
-    pub fn get_body_string(&self) -> String {
-        String::from_utf8_lossy(self.get_body()).into_owned()
+    /// - `&mut Self` - The builder for chaining.
+    fn insert_header(&mut self, key: &str, value: String) -> &mut Self {
+        let mut values: RequestHeadersValue = VecDeque::with_capacity(1);
+        values.push_back(value);
+        self.request.headers.insert(key.to_string(), values);
+        self
     }
 
-    /// Deserializes the body content of the request as_ref a specified type `T`.
+    /// Sets a header, replacing any existing values.
     ///
-    /// This method first retrieves the body content as a byte slice using `self.get_body()`.
-    /// It then attempts to deserialize the byte slice as_ref the specified type `T` using `json_from_slice`.
+    /// The key is stored in lowercase.
     ///
     /// # Arguments
     ///
-    /// - `DeserializeOwned` - The target type to deserialize as_ref (must implement DeserializeOwned).
+    /// - `AsRef<str>` - The header key.
+    /// - `AsRef<str>` - The header value.
     ///
     /// # Returns
     ///
-    /// - `Result<T, serde_json::Error>` - The deserialization result.
-    #[inline(always)]
-    pub fn try_get_body_json<T>(&self) -> Result<T, serde_json::Error>
+    /// - `Result<&mut Self, RequestError>` - The builder for chaining, `InvalidHeaderName`
+    ///   if the key is not a token, or `InvalidHeaderValue` if the value contains a CR, LF
+    ///   or NUL character.
+    pub fn header<K, V>(&mut self, key: K, value: V) -> Result<&mut Self, RequestError>
     where
-        T: DeserializeOwned,
+        K: AsRef<str>,
+        V: AsRef<str>,
     {
-        serde_json::from_slice(self.get_body())
+        Http::check_header(key.as_ref(), value.as_ref())?;
+        Ok(self.insert_header(
+            &key.as_ref().to_ascii_lowercase(),
+            value.as_ref().to_string(),
+        ))
     }
 
-    /// Deserializes the body content of the request as_ref a specified type `T`.
+    /// Adds a header value, keeping any existing values.
     ///
-    /// This method first retrieves the body content as a byte slice using `self.get_body()`.
-    /// It then attempts to deserialize the byte slice as_ref the specified type `T` using `json_from_slice`.
+    /// The key is stored in lowercase.
     ///
     /// # Arguments
     ///
-    /// - `DeserializeOwned` - The target type to deserialize as_ref (must implement DeserializeOwned).
+    /// - `AsRef<str>` - The header key.
+    /// - `AsRef<str>` - The header value.
     ///
     /// # Returns
     ///
-    /// - `T` - The deserialized body content.
-    ///
-    /// # Panics
-    ///
-    /// This function will panic if the deserialization fails.
-    #[inline(always)]
-    pub fn get_body_json<T>(&self) -> T
+    /// - `Result<&mut Self, RequestError>` - The builder for chaining, `InvalidHeaderName`
+    ///   if the key is not a token, or `InvalidHeaderValue` if the value contains a CR, LF
+    ///   or NUL character.
+    pub fn add_header<K, V>(&mut self, key: K, value: V) -> Result<&mut Self, RequestError>
     where
-        T: DeserializeOwned,
+        K: AsRef<str>,
+        V: AsRef<str>,
     {
-        self.try_get_body_json().unwrap()
+        Http::check_header(key.as_ref(), value.as_ref())?;
+        self.request
+            .headers
+            .entry(key.as_ref().to_ascii_lowercase())
+            .or_default()
+            .push_back(value.as_ref().to_string());
+        Ok(self)
     }
 
-    /// Checks whether the WebSocket upgrade is enabled for this request.
+    /// Sets the `Last-Event-ID` header used to resume a Server-Sent Events stream.
     ///
-    /// This method determines if the `UPGRADE` header indicates a WebSocket connection.
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The id of the last event received.
     ///
     /// # Returns
     ///
-    /// - `bool` - Whether WebSocket upgrade is enabled.
+    /// - `Result<&mut Self, RequestError>` - The builder for chaining, or
+    ///   `InvalidHeaderValue` if the id contains a CR, LF or NUL character.
     #[inline(always)]
-    pub fn is_ws_upgrade_type(&self) -> bool {
-        self.get_upgrade_type().is_ws()
+    pub fn last_event_id<I>(&mut self, id: I) -> Result<&mut Self, RequestError>
+    where
+        I: AsRef<str>,
+    {
+        self.header(LAST_EVENT_ID, id)
     }
 
-    /// Checks if the current upgrade type is HTTP/2 cleartext (h2c).
+    /// Sets the raw request body.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// - `bool` - Whether the upgrade type is h2c.
-    #[inline(always)]
-    pub fn is_h2c_upgrade_type(&self) -> bool {
-        self.get_upgrade_type().is_h2c()
-    }
-
-    /// Checks if the current upgrade type is TLS (any version).
+    /// - `Into<RequestBody>` - The body bytes.
     ///
     /// # Returns
     ///
-    /// - `bool` - Whether the upgrade type is TLS.
+    /// - `&mut Self` - The builder for chaining.
     #[inline(always)]
-    pub fn is_tls_upgrade_type(&self) -> bool {
-        self.get_upgrade_type().is_tls()
+    pub fn body<B>(&mut self, body: B) -> &mut Self
+    where
+        B: Into<RequestBody>,
+    {
+        self.request.body = body.into();
+        self
     }
 
-    /// Checks whether the upgrade type is unknown.
+    /// Sets a JSON body and the matching `Content-Type`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Serialize` - The value to serialize.
     ///
     /// # Returns
     ///
-    /// - `bool` - Whether the upgrade type is unknown.
-    #[inline(always)]
-    pub fn is_unknown_upgrade_type(&self) -> bool {
-        self.get_upgrade_type().is_unknown()
+    /// - `Result<&mut Self, serde_json::Error>` - The builder for chaining, or an error
+    ///   if the value cannot be serialized.
+    pub fn json<T>(&mut self, value: &T) -> Result<&mut Self, serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let body: RequestBody = serde_json::to_vec(value)?;
+        Ok(self
+            .insert_header(CONTENT_TYPE, APPLICATION_JSON.to_string())
+            .body(body))
     }
 
-    /// Determines if a keep-alive connection should be enabled for this request.
+    /// Sets a `application/x-www-form-urlencoded` body and the matching `Content-Type`.
     ///
-    /// This function checks the `Connection` header and the HTTP version to determine
-    /// if keep-alive should be enabled. The logic is as follows:
+    /// # Arguments
     ///
-    /// 1. If the `Connection` header exists:
-    ///    - Returns `true` if the header value is "keep-alive" (case-insensitive).
-    ///    - Returns `false` if the header value is "close" (case-insensitive).
-    /// 2. If no `Connection` header is present:
-    ///    - Returns `true` if the HTTP version is 1.1 or higher.
-    ///    - Returns `false` otherwise.
+    /// - `&Serialize` - The value to serialize, such as a struct or a list of pairs.
     ///
     /// # Returns
     ///
-    /// - `bool` - Whether keep-alive should be enabled.
-    #[inline(always)]
-    pub fn is_enable_keep_alive(&self) -> bool {
-        if let Some(connection_value) = self.try_get_header_back(CONNECTION) {
-            if connection_value.eq_ignore_ascii_case(KEEP_ALIVE) {
-                return true;
-            } else if connection_value.eq_ignore_ascii_case(CLOSE) {
-                return self.is_ws_upgrade_type();
-            }
-        }
-        self.get_version().is_http1_1_or_higher() || self.is_ws_upgrade_type()
+    /// - `Result<&mut Self, serde_urlencoded::ser::Error>` - The builder for chaining, or
+    ///   an error if the value cannot be serialized.
+    pub fn form<T>(&mut self, value: &T) -> Result<&mut Self, serde_urlencoded::ser::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let body: String = serde_urlencoded::to_string(value)?;
+        Ok(self
+            .insert_header(CONTENT_TYPE, FORM_URLENCODED.to_string())
+            .body(body))
     }
 
-    /// Determines if keep-alive should be disabled for this request.
+    /// Finishes the request.
+    ///
+    /// The `Host` header is set from the URL if missing, and `Content-Length` is set
+    /// for a non-empty body or a method that usually carries one, unless a transfer
+    /// encoding is set.
     ///
     /// # Returns
     ///
-    /// - `bool` - Whether keep-alive should be disabled.
-    #[inline(always)]
-    pub fn is_disable_keep_alive(&self) -> bool {
-        !self.is_enable_keep_alive()
+    /// - `Request` - The assembled request.
+    pub fn build(&self) -> Request {
+        let mut request: Request = self.request.clone();
+        if !request.host.is_empty() && !request.has_header(HOST) {
+            let host: RequestHost = request.host.clone();
+            request
+                .headers
+                .entry(HOST.to_string())
+                .or_default()
+                .push_back(host);
+        }
+        let expects_body: bool = !request.body.is_empty()
+            || matches!(request.method, Method::Post | Method::Put | Method::Patch);
+        if expects_body && !request.has_header(TRANSFER_ENCODING) {
+            let mut values: RequestHeadersValue = VecDeque::with_capacity(1);
+            values.push_back(request.body.len().to_string());
+            request.headers.insert(CONTENT_LENGTH.to_string(), values);
+        }
+        request
     }
 }
@@ -1,3 +1,4 @@
+mod r#const;
 mod r#enum;
 mod r#impl;
 mod r#struct;
@@ -5,4 +6,4 @@ mod r#struct;
 mod test;
 mod r#type;
 
-pub use {r#enum::*, r#struct::*, r#type::*};
+pub use {r#const::*, r#enum::*, r#struct::*, r#type::*};
@@ -8,6 +8,7 @@ use crate::*;
 /// Since all fields implement `Copy`, this struct is lightweight
 /// and can be easily cloned and shared.
 #[derive(Clone, Copy, Data, Debug, Deserialize, DisplayDebug, Eq, New, PartialEq, Serialize)]
+#[serde(default)]
 pub struct RequestConfig {
     /// Buffer size for reading operations.
     #[get(type(copy))]
@@ -37,6 +38,31 @@ pub struct RequestConfig {
     #[get(type(copy))]
     #[set]
     pub(super) read_timeout_ms: u64,
+    /// Whether header parsing skips the strict RFC 9112 checks against request smuggling.
+    #[get(type(copy))]
+    #[set]
+    #[new(skip)]
+    pub(super) lenient_header_parsing: bool,
+    /// Maximum payload size for a single WebSocket frame in bytes.
+    #[get(type(copy))]
+    #[set]
+    #[new(skip)]
+    pub(super) max_ws_frame_size: usize,
+    /// Maximum size for a reassembled WebSocket message in bytes.
+    #[get(type(copy))]
+    #[set]
+    #[new(skip)]
+    pub(super) max_ws_message_size: usize,
+    /// Maximum size for a single part of a multipart body in bytes.
+    #[get(type(copy))]
+    #[set]
+    #[new(skip)]
+    pub(super) max_multipart_part_size: usize,
+    /// Maximum number of query parameters allowed in a request.
+    #[get(type(copy))]
+    #[set]
+    #[new(skip)]
+    pub(super) max_query_count: usize,
 }
 
 /// HTTP request representation.
@@ -52,16 +78,40 @@ pub struct Request {
     pub(super) host: RequestHost,
     /// HTTP protocol version.
     pub(super) version: RequestVersion,
+    /// Form of the request target.
+    #[get(type(copy))]
+    pub(super) target_form: RequestTarget,
+    /// Scheme of the absolute URL the request was pointed at, empty otherwise.
+    pub(super) scheme: RequestScheme,
     /// Request path.
     pub(super) path: RequestPath,
-    /// URL query parameters.
+    /// Raw query string as received, without the leading `?`.
+    ///
+    /// Kept in sync by `set_raw_query` and `set_querys`; changes made through
+    /// `get_mut_querys` leave it stale until the next call to either setter.
+    #[set(skip)]
+    pub(super) raw_query: RequestRawQuery,
+    /// Decoded URL query parameters.
+    #[set(skip)]
     pub(super) querys: RequestQuerys,
     /// HTTP headers collection.
     pub(super) headers: RequestHeaders,
+    /// Trailer fields received after a chunked body, kept apart from the headers.
+    pub(super) trailers: RequestHeaders,
     /// Request body content.
     pub(super) body: RequestBody,
 }
 
+/// Builder for outbound HTTP requests.
+///
+/// Collects the method, URL, headers and body of a request and fills in the
+/// `Host` and `Content-Length` headers when the request is built.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestBuilder {
+    /// The request being assembled.
+    pub(super) request: Request,
+}
+
 /// HTTP request representation.
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, New, PartialEq, Serialize)]
 pub(crate) struct Http;
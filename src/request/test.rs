@@ -53,10 +53,10 @@ fn request_query_operations() {
     let mut request: Request = Request::default();
     request
         .querys
-        .insert("key1".to_string(), "value1".to_string());
+        .insert("key1".to_string(), VecDeque::from(["value1".to_string()]));
     request
         .querys
-        .insert("key2".to_string(), "value2".to_string());
+        .insert("key2".to_string(), VecDeque::from(["value2".to_string()]));
     assert_eq!(request.try_get_query("key1"), Some("value1".to_string()));
     assert_eq!(request.try_get_query("key2"), Some("value2".to_string()));
     assert_eq!(request.try_get_query("key3"), None);
@@ -322,3 +322,379 @@ fn request_get_cookies_panic() {
     let request: Request = Request::default();
     let _: Cookies = request.get_cookies();
 }
+
+#[tokio::test]
+async fn request_http_from_stream_chunked_body() {
+    let listener: tokio::net::TcpListener =
+        tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: std::net::SocketAddr = listener.local_addr().unwrap();
+    let client: tokio::task::JoinHandle<TcpStream> = tokio::spawn(async move {
+        let mut client: TcpStream = TcpStream::connect(address).await.unwrap();
+        client
+            .write_all(
+                b"POST /upload HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n5;ext=1\r\nhello\r\n6\r\n world\r\n0\r\nX-Trailer: done\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        client
+    });
+    let (server, _): (TcpStream, std::net::SocketAddr) = listener.accept().await.unwrap();
+    let _client: TcpStream = client.await.unwrap();
+    let stream: ArcRwLockStream = ArcRwLockStream::from_stream(server);
+    let request: Request = Request::http_from_stream(&stream, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(request.get_body(), b"hello world");
+    assert_eq!(
+        request
+            .get_trailers()
+            .get("x-trailer")
+            .unwrap()
+            .back()
+            .unwrap(),
+        "done"
+    );
+    assert!(!request.has_header("x-trailer"));
+}
+
+#[tokio::test]
+async fn request_http_from_duplex_stream() {
+    let (mut client, server): (tokio::io::DuplexStream, tokio::io::DuplexStream) =
+        tokio::io::duplex(1024);
+    client
+        .write_all(b"POST /duplex HTTP/1.1\r\nHost: memory\r\nContent-Length: 4\r\n\r\ndata")
+        .await
+        .unwrap();
+    let stream: ArcRwLockIoStream<tokio::io::DuplexStream> = ArcRwLockStream::from_io(server);
+    let request: Request = Request::http_from_stream(&stream, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(request.get_path(), "/duplex");
+    assert_eq!(request.get_host(), "memory");
+    assert_eq!(request.get_body(), b"data");
+    stream
+        .try_send(b"HTTP/1.1 204 No Content\r\n\r\n")
+        .await
+        .unwrap();
+    stream.try_flush().await.unwrap();
+    let mut response: Vec<u8> = vec![0; 64];
+    let size: usize = client.read(&mut response).await.unwrap();
+    assert_eq!(&response[..size], b"HTTP/1.1 204 No Content\r\n\r\n");
+}
+
+#[tokio::test]
+async fn request_read_does_not_block_send() {
+    let (mut client, server): (tokio::io::DuplexStream, tokio::io::DuplexStream) =
+        tokio::io::duplex(1024);
+    let stream: ArcRwLockIoStream<tokio::io::DuplexStream> = ArcRwLockStream::from_io(server);
+    let reader_stream: ArcRwLockIoStream<tokio::io::DuplexStream> = stream.clone();
+    let reader: tokio::task::JoinHandle<Result<Request, RequestError>> = tokio::spawn(async move {
+        Request::http_from_stream(&reader_stream, &RequestConfig::default()).await
+    });
+    tokio::task::yield_now().await;
+    timeout(Duration::from_secs(1), stream.try_send(b"pushed"))
+        .await
+        .unwrap()
+        .unwrap();
+    let mut pushed: Vec<u8> = vec![0; 6];
+    client.read_exact(&mut pushed).await.unwrap();
+    assert_eq!(pushed, b"pushed");
+    client
+        .write_all(b"GET /late HTTP/1.1\r\n\r\n")
+        .await
+        .unwrap();
+    let request: Request = reader.await.unwrap().unwrap();
+    assert_eq!(request.get_path(), "/late");
+}
+
+const WS_MASK_KEY: Option<[u8; 4]> = Some([0x12, 0x34, 0x56, 0x78]);
+
+#[tokio::test]
+async fn request_ws_from_stream_fragmented_message() {
+    let (mut client, server): (tokio::io::DuplexStream, tokio::io::DuplexStream) =
+        tokio::io::duplex(1024);
+    let frames: Vec<u8> = [
+        WebSocketFrame::encode_ws_frame(false, WebSocketOpcode::Text, WS_MASK_KEY, b"hel"),
+        WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Ping, WS_MASK_KEY, b"ping"),
+        WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Continuation, WS_MASK_KEY, b"lo"),
+    ]
+    .concat();
+    client.write_all(&frames).await.unwrap();
+    let stream: ArcRwLockIoStream<tokio::io::DuplexStream> = ArcRwLockStream::from_io(server);
+    let request: Request = Request::default()
+        .ws_from_stream(&stream, &RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(request.get_body(), b"hello");
+}
+
+#[tokio::test]
+async fn request_ws_from_stream_invalid_messages() {
+    let cases: [(Vec<u8>, RequestError); 3] = [
+        (
+            WebSocketFrame::encode_ws_frame(
+                true,
+                WebSocketOpcode::Continuation,
+                WS_MASK_KEY,
+                b"orphan",
+            ),
+            RequestError::WebSocketInvalidFragmentation(HttpStatus::BadRequest),
+        ),
+        (
+            WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Text, WS_MASK_KEY, [0xFF, 0xFE]),
+            RequestError::WebSocketInvalidUtf8(HttpStatus::BadRequest),
+        ),
+        (
+            [
+                WebSocketFrame::encode_ws_frame(
+                    false,
+                    WebSocketOpcode::Binary,
+                    WS_MASK_KEY,
+                    [0; 6],
+                ),
+                WebSocketFrame::encode_ws_frame(
+                    true,
+                    WebSocketOpcode::Continuation,
+                    WS_MASK_KEY,
+                    [0; 6],
+                ),
+            ]
+            .concat(),
+            RequestError::WebSocketFrameTooLarge(HttpStatus::PayloadTooLarge),
+        ),
+    ];
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_ws_message_size(10);
+    for (frames, error) in cases {
+        let (mut client, server): (tokio::io::DuplexStream, tokio::io::DuplexStream) =
+            tokio::io::duplex(1024);
+        client.write_all(&frames).await.unwrap();
+        let stream: ArcRwLockIoStream<tokio::io::DuplexStream> = ArcRwLockStream::from_io(server);
+        let result: Result<Request, RequestError> =
+            Request::default().ws_from_stream(&stream, &config).await;
+        assert_eq!(result, Err(error));
+    }
+}
+
+#[test]
+fn request_builder_build_round_trip() {
+    let url: HttpUrlComponents =
+        HttpUrlComponents::parse("http://example.com:8080/api/items?b=2&a=1").unwrap();
+    let request: Request = RequestBuilder::new()
+        .method(Method::Post)
+        .url(&url)
+        .header("X-Trace", "abc")
+        .unwrap()
+        .json(&serde_json::json!({"name": "item"}))
+        .unwrap()
+        .build();
+    assert_eq!(request.get_host(), "example.com:8080");
+    assert_eq!(request.get_target(), "/api/items?b=2&a=1");
+    assert_eq!(request.get_header_back(CONTENT_TYPE), APPLICATION_JSON);
+    assert_eq!(request.get_header_back(CONTENT_LENGTH), "15");
+    let data: RequestData = request.build();
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    match parser.feed(&data).unwrap() {
+        RequestParseStatus::Complete(parsed, consumed) => {
+            assert_eq!(consumed, data.len());
+            assert_eq!(parsed.get_method(), &Method::Post);
+            assert_eq!(parsed.get_path(), "/api/items");
+            assert_eq!(parsed.get_host(), "example.com:8080");
+            assert_eq!(parsed.get_query("a"), "1");
+            assert_eq!(parsed.get_header_back("x-trace"), "abc");
+            assert_eq!(parsed.get_body(), br#"{"name":"item"}"#);
+        }
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+}
+
+#[test]
+fn request_builder_form_and_empty_post() {
+    let request: Request = RequestBuilder::new()
+        .method(Method::Post)
+        .form(&[("user", "a b"), ("id", "7")])
+        .unwrap()
+        .build();
+    assert_eq!(request.get_body(), b"user=a+b&id=7");
+    assert_eq!(request.get_header_back(CONTENT_TYPE), FORM_URLENCODED);
+    let empty: Request = RequestBuilder::new().method(Method::Put).build();
+    assert_eq!(empty.get_header_back(CONTENT_LENGTH), "0");
+    let get: Request = RequestBuilder::new().build();
+    assert!(!get.has_header(CONTENT_LENGTH));
+    assert_eq!(get.build(), b"GET / HTTP/1.1\r\n\r\n");
+}
+
+#[test]
+fn request_builder_rejects_header_injection() {
+    let mut builder: RequestBuilder = RequestBuilder::new();
+    assert_eq!(
+        builder.header("X-Trace", "abc\r\nX-Injected: 1").err(),
+        Some(RequestError::InvalidHeaderValue(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        builder
+            .add_header("X-Trace", "abc\n\r\nGET /admin HTTP/1.1")
+            .err(),
+        Some(RequestError::InvalidHeaderValue(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        builder.header("X-Bad\r\nX-Injected", "1").err(),
+        Some(RequestError::InvalidHeaderName(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        builder.last_event_id("1\0").err(),
+        Some(RequestError::InvalidHeaderValue(HttpStatus::BadRequest))
+    );
+    let request: Request = builder.build();
+    assert!(request.get_headers().is_empty());
+    assert_eq!(request.check_headers(), Ok(()));
+    let mut request: Request = request;
+    request.set_host("example.com\r\nX-Injected: 1".to_string());
+    assert_eq!(
+        request.check_headers(),
+        Err(RequestError::InvalidHeaderValue(HttpStatus::BadRequest))
+    );
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct SearchParams {
+    user: String,
+    id: u32,
+    tag: Option<String>,
+}
+
+#[test]
+fn request_typed_form_and_querys() {
+    let url: HttpUrlComponents =
+        HttpUrlComponents::parse("http://example.com/search?user=a%20b&id=7&tag=x%2By").unwrap();
+    let request: Request = RequestBuilder::new()
+        .method(Method::Post)
+        .url(&url)
+        .form(&[("user", "c+d"), ("id", "8")])
+        .unwrap()
+        .build();
+    assert_eq!(
+        request.get_querys_as::<SearchParams>(),
+        SearchParams {
+            user: "a b".to_string(),
+            id: 7,
+            tag: Some("x+y".to_string()),
+        }
+    );
+    assert_eq!(
+        request.get_body_form::<SearchParams>(),
+        SearchParams {
+            user: "c+d".to_string(),
+            id: 8,
+            tag: None,
+        }
+    );
+    let invalid: Request = RequestBuilder::new()
+        .method(Method::Post)
+        .body("user=a&id=x")
+        .build();
+    assert_eq!(
+        invalid.try_get_body_form::<SearchParams>(),
+        Err(RequestError::InvalidFormBody(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        invalid.try_get_querys_as::<SearchParams>(),
+        Err(RequestError::InvalidQuery(HttpStatus::BadRequest))
+    );
+}
+
+#[test]
+fn request_multi_valued_decoded_querys() {
+    let line: &str = "GET /search?tag=a%20b&tag=c+d&empty&x%3Dy=1%262 HTTP/1.1";
+    let request: Request = Http::parse_request_line(line, &RequestConfig::default()).unwrap();
+    assert_eq!(
+        request.get_raw_query(),
+        "tag=a%20b&tag=c+d&empty&x%3Dy=1%262"
+    );
+    assert_eq!(request.get_query("tag"), "a b");
+    assert_eq!(
+        request.get_query_values("tag"),
+        VecDeque::from(["a b".to_string(), "c d".to_string()])
+    );
+    assert_eq!(request.get_query("empty"), "");
+    assert_eq!(request.get_query("x=y"), "1&2");
+    assert_eq!(
+        request.get_target(),
+        "/search?tag=a%20b&tag=c+d&empty&x%3Dy=1%262"
+    );
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_query_count(3);
+    assert_eq!(
+        Http::parse_request_line(line, &config),
+        Err(RequestError::TooManyQuerys(HttpStatus::BadRequest))
+    );
+    config.set_max_query_count(DEFAULT_LOW_SECURITY_MAX_QUERY_COUNT);
+    assert!(Http::parse_request_line(line, &config).is_ok());
+}
+
+#[test]
+fn request_target_keeps_raw_query_in_sync() {
+    let line: &str = "GET /search?z=1&a=%7E HTTP/1.1";
+    let mut request: Request = Http::parse_request_line(line, &RequestConfig::default()).unwrap();
+    assert_eq!(request.get_target(), "/search?z=1&a=%7E");
+    let mut querys: RequestQuerys = hash_map_xx_hash3_64();
+    querys.insert("q".to_string(), VecDeque::from(["a b".to_string()]));
+    request.set_querys(querys);
+    assert_eq!(request.get_raw_query(), "q=a+b");
+    assert_eq!(request.get_target(), "/search?q=a+b");
+    request
+        .get_mut_querys()
+        .insert("n".to_string(), VecDeque::from(["1".to_string()]));
+    assert_eq!(request.get_raw_query(), "q=a+b");
+    assert_eq!(request.get_target(), "/search?n=1&q=a+b");
+    request.set_raw_query("b=2&a=1");
+    assert_eq!(request.get_target(), "/search?b=2&a=1");
+}
+
+#[test]
+fn request_normalized_path_and_safe_join() {
+    let mut request: Request = Request::default();
+    for (raw, normalized) in [
+        ("/a/./b/../c", "/a/c"),
+        ("//double//slash/", "/double/slash/"),
+        ("/static/..%2f..%2fetc/passwd", "/etc/passwd"),
+        ("/../../x", "/x"),
+        ("/dir/.", "/dir/"),
+        ("/caf%C3%A9%20au+lait", "/café au+lait"),
+        ("", "/"),
+    ] {
+        request.set_path(raw.to_string());
+        assert_eq!(request.get_normalized_path(), normalized);
+        assert_eq!(request.get_path(), raw);
+    }
+    request.set_path("/a%00b".to_string());
+    assert_eq!(
+        request.try_get_normalized_path(),
+        Err(RequestError::InvalidPath(HttpStatus::BadRequest))
+    );
+    let root: &Path = Path::new("/srv/www");
+    request.set_path("/css/./site.css".to_string());
+    assert_eq!(
+        request.try_join_path(root),
+        Ok(root.join("css").join("site.css"))
+    );
+    request.set_path("/a/../index.html".to_string());
+    assert_eq!(request.try_join_path(root), Ok(root.join("index.html")));
+    for raw in [
+        "/static/..%2f..%2fetc/passwd",
+        "/..",
+        "/a%5C..%5C..%5Cwin.ini",
+        "/a\\b",
+    ] {
+        request.set_path(raw.to_string());
+        assert_eq!(
+            request.try_join_path(root),
+            Err(RequestError::PathTraversal(HttpStatus::Forbidden))
+        );
+    }
+    request.set_path("/x%00.html".to_string());
+    assert_eq!(
+        request.try_join_path(root),
+        Err(RequestError::InvalidPath(HttpStatus::BadRequest))
+    );
+}
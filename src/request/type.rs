@@ -9,21 +9,33 @@ pub type RequestHost = String;
 /// An alias for `HttpVersion`, representing the HTTP version.
 pub type RequestVersion = HttpVersion;
 
+/// An alias for `String`, representing the lowercase scheme of the request URL.
+pub type RequestScheme = String;
+
 /// An alias for `String`, representing the path portion of the request URL.
 pub type RequestPath = String;
 
 /// An alias for `String`, representing the key type for request query parameters.
 pub type RequestQuerysKey = String;
 
-/// An alias for `String`, representing the value type for request query parameters.
-pub type RequestQuerysValue = String;
+/// An alias for `String`, representing a single decoded value of a request query parameter.
+pub type RequestQuerysValueItem = String;
+
+/// An alias for `VecDeque<RequestQuerysValueItem>`, representing all values of a request query parameter in order.
+pub type RequestQuerysValue = VecDeque<RequestQuerysValueItem>;
 
 /// An alias for `HashMapXxHash3_64<RequestQuerysKey, RequestQuerysValue>`, representing all query parameters parsed from the request URL.
 pub type RequestQuerys = HashMapXxHash3_64<RequestQuerysKey, RequestQuerysValue>;
 
+/// An alias for `String`, representing the raw, still percent-encoded query string of the request URL.
+pub type RequestRawQuery = String;
+
 /// An alias for `Vec<u8>`, representing the raw binary body of the request.
 pub type RequestBody = Vec<u8>;
 
+/// An alias for `Vec<u8>`, representing the request serialized in HTTP/1.1 wire format.
+pub type RequestData = Vec<u8>;
+
 /// An alias for `String`, representing the request body as a UTF-8 encoded string.
 pub type RequestBodyString = String;
 
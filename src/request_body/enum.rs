@@ -0,0 +1,13 @@
+use crate::*;
+
+/// Represents how the remaining part of a request body is delimited.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RequestBodyFraming {
+    /// No body bytes remain.
+    #[default]
+    Empty,
+    /// A `Content-Length` body, including the number of bytes still expected.
+    Length(usize),
+    /// A `Transfer-Encoding: chunked` body.
+    Chunked,
+}
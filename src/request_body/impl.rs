@@ -0,0 +1,159 @@
+use crate::*;
+
+impl<R, W> RequestBodyStream<R, W>
+where
+    R: AsyncReadHalf,
+    W: AsyncWriteHalf,
+{
+    /// Creates a new `RequestBodyStream`.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The stream the body is read from.
+    /// - `RequestConfig` - Configuration providing the buffer size, body limit and read timeout.
+    /// - `RequestBodyFraming` - How the body is delimited.
+    /// - `Vec<u8>` - Bytes already read from the stream past the request headers.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBodyStream<R, W>` - A reader positioned at the start of the body.
+    #[inline(always)]
+    pub fn new(
+        stream: ArcRwLockStream<R, W>,
+        config: RequestConfig,
+        framing: RequestBodyFraming,
+        buffer: Vec<u8>,
+    ) -> Self {
+        Self {
+            stream,
+            config,
+            framing,
+            buffer,
+            chunked_decoder: ChunkedDecoder::new(config),
+            received_size: 0,
+        }
+    }
+
+    /// Checks whether the whole body has been read.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether no body bytes remain.
+    #[inline(always)]
+    pub fn is_done(&self) -> bool {
+        self.framing == RequestBodyFraming::Empty
+    }
+
+    /// Gets the trailer headers sent after a chunked body.
+    ///
+    /// Trailers are only available once the body has been read to the end.
+    ///
+    /// # Returns
+    ///
+    /// - `&RequestHeaders` - The trailer headers.
+    #[inline(always)]
+    pub fn get_trailers(&self) -> &RequestHeaders {
+        self.chunked_decoder.get_trailers()
+    }
+
+    /// Takes the bytes that were read from the stream past the end of the body.
+    ///
+    /// These bytes belong to the next request on a keep-alive connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The unread bytes.
+    #[inline(always)]
+    pub fn take_buffer(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /// Reads more bytes from the stream into the buffer.
+    ///
+    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if bytes were read, or an error if the read
+    ///   failed, timed out or the client closed the connection.
+    async fn fill_buffer(&mut self) -> Result<(), RequestError> {
+        self.buffer.reserve(self.config.get_buffer_size());
+        let timeout_ms: u64 = self.config.get_read_timeout_ms();
+        let mut reader: RwLockWriteGuard<'_, R> = self.stream.read_mut().await;
+        let size: usize = if timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
+            reader.read_buf(&mut self.buffer).await?
+        } else {
+            let duration: Duration = Duration::from_millis(timeout_ms);
+            timeout(duration, reader.read_buf(&mut self.buffer)).await??
+        };
+        if size == 0 {
+            return Err(RequestError::ClientClosedConnection(HttpStatus::BadRequest));
+        }
+        Ok(())
+    }
+
+    /// Reads the next chunk of the body.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<RequestBody>, RequestError>` - The next body bytes, `None` once the
+    ///   body is complete, or an error if the body is malformed, exceeds the configured
+    ///   limit or the connection fails.
+    pub async fn next_chunk(&mut self) -> Result<Option<RequestBody>, RequestError> {
+        loop {
+            match self.framing {
+                RequestBodyFraming::Empty => return Ok(None),
+                RequestBodyFraming::Length(remaining) => {
+                    if self.buffer.is_empty() {
+                        self.fill_buffer().await?;
+                    }
+                    let size: usize = remaining.min(self.buffer.len());
+                    let chunk: RequestBody = self.buffer.drain(..size).collect();
+                    self.framing = if size < remaining {
+                        RequestBodyFraming::Length(remaining - size)
+                    } else {
+                        RequestBodyFraming::Empty
+                    };
+                    self.received_size += size;
+                    return Ok(Some(chunk));
+                }
+                RequestBodyFraming::Chunked => {
+                    if self.buffer.is_empty() {
+                        self.fill_buffer().await?;
+                    }
+                    let mut chunk: RequestBody = Vec::new();
+                    let consumed: usize = self.chunked_decoder.decode(&self.buffer, &mut chunk)?;
+                    self.buffer.drain(..consumed);
+                    if self.chunked_decoder.is_done() {
+                        self.framing = RequestBodyFraming::Empty;
+                    }
+                    if !chunk.is_empty() {
+                        self.received_size += chunk.len();
+                        return Ok(Some(chunk));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads the rest of the body into memory.
+    ///
+    /// At most `MAX_BODY_PREALLOCATION_SIZE` bytes are reserved up front for a
+    /// `Content-Length` body; the buffer grows as the remaining bytes arrive.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<RequestBody, RequestError>` - The remaining body bytes, or an error.
+    pub async fn read_to_end(&mut self) -> Result<RequestBody, RequestError> {
+        let mut body: RequestBody = match self.framing {
+            RequestBodyFraming::Length(remaining) => {
+                Vec::with_capacity(remaining.min(MAX_BODY_PREALLOCATION_SIZE))
+            }
+            _ => Vec::new(),
+        };
+        while let Some(chunk) = self.next_chunk().await? {
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::*;
+
+/// Streaming reader for the body of a request whose headers were already parsed.
+///
+/// The body is yielded in chunks as it arrives instead of being buffered in
+/// full. The body size limit is enforced as chunks are decoded, and each read
+/// from the stream is bounded by the configured read timeout.
+#[derive(Debug, Getter)]
+pub struct RequestBodyStream<R, W> {
+    /// The stream the body is read from.
+    #[get(skip)]
+    pub(super) stream: ArcRwLockStream<R, W>,
+    /// Configuration providing the buffer size, body limit and read timeout.
+    #[get(type(copy))]
+    pub(super) config: RequestConfig,
+    /// How the remaining part of the body is delimited.
+    #[get(type(copy))]
+    pub(super) framing: RequestBodyFraming,
+    /// Bytes read from the stream that have not been yielded yet.
+    #[get(skip)]
+    pub(super) buffer: Vec<u8>,
+    /// The decoder used for chunked request bodies.
+    #[get(skip)]
+    pub(super) chunked_decoder: ChunkedDecoder,
+    /// The number of body bytes yielded so far.
+    #[get(type(copy))]
+    pub(super) received_size: usize,
+}
@@ -0,0 +1,86 @@
+use crate::*;
+
+use tokio::io::{DuplexStream, duplex};
+
+#[tokio::test]
+async fn request_body_stream_content_length_in_chunks() {
+    let (client, mut server): (DuplexStream, DuplexStream) = duplex(1024);
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_buffer_size(4);
+    server
+        .write_all(b"POST /upload HTTP/1.1\r\nContent-Length: 10\r\n\r\n0123")
+        .await
+        .unwrap();
+    let (request, mut body): (Request, RequestBodyStream<_, _>) =
+        Request::http_head_from_stream(&stream, &config)
+            .await
+            .unwrap();
+    assert_eq!(request.get_path(), "/upload");
+    assert!(request.get_body().is_empty());
+    assert_eq!(body.get_framing(), RequestBodyFraming::Length(10));
+    server.write_all(b"456789GET / HTTP/1.1\r\n").await.unwrap();
+    let mut received: Vec<u8> = Vec::new();
+    while let Some(chunk) = body.next_chunk().await.unwrap() {
+        assert!(chunk.len() <= 6);
+        received.extend_from_slice(&chunk);
+    }
+    assert_eq!(received, b"0123456789");
+    assert!(body.is_done());
+    assert_eq!(body.get_received_size(), 10);
+    assert!(b"GET / HTTP/1.1\r\n".starts_with(&body.take_buffer()));
+}
+
+#[tokio::test]
+async fn request_body_stream_chunked_limit_and_trailers() {
+    let (client, mut server): (DuplexStream, DuplexStream) = duplex(1024);
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    server
+        .write_all(b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\nX-Sum: 5\r\n\r\n")
+        .await
+        .unwrap();
+    let (_, mut body): (Request, RequestBodyStream<_, _>) =
+        Request::http_head_from_stream(&stream, &RequestConfig::default())
+            .await
+            .unwrap();
+    assert_eq!(body.read_to_end().await.unwrap(), b"abcde");
+    assert_eq!(
+        body.get_trailers().get("x-sum").unwrap().back().unwrap(),
+        "5"
+    );
+    let (client, mut server): (DuplexStream, DuplexStream) = duplex(1024);
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_body_size(4);
+    server
+        .write_all(b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n3\r\ndef\r\n")
+        .await
+        .unwrap();
+    let (_, mut body): (Request, RequestBodyStream<_, _>) =
+        Request::http_head_from_stream(&stream, &config)
+            .await
+            .unwrap();
+    assert_eq!(
+        body.read_to_end().await,
+        Err(RequestError::ContentLengthTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+}
+
+#[tokio::test]
+async fn request_body_stream_does_not_preallocate_declared_length() {
+    let (client, mut server): (DuplexStream, DuplexStream) = duplex(1024);
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    server
+        .write_all(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 18446744073709551615\r\n\r\nab")
+        .await
+        .unwrap();
+    drop(server);
+    let (_, mut body): (Request, RequestBodyStream<_, _>) =
+        Request::http_head_from_stream(&stream, &RequestConfig::low_security())
+            .await
+            .unwrap();
+    assert_eq!(body.get_framing(), RequestBodyFraming::Length(usize::MAX));
+    assert!(body.read_to_end().await.is_err());
+}
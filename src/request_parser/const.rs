@@ -0,0 +1,7 @@
+use crate::*;
+
+/// The largest body buffer reserved up front from a declared `Content-Length`.
+///
+/// Bigger bodies grow the buffer as their bytes arrive, so a client cannot make the
+/// server allocate memory for data it never sends.
+pub const MAX_BODY_PREALLOCATION_SIZE: usize = KB_64 * 16;
@@ -0,0 +1,35 @@
+use crate::*;
+
+/// Represents the parsing step of an incremental HTTP/1.x request parser.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RequestParserState {
+    /// Waiting for the request line, skipping any leading empty lines.
+    #[default]
+    FirstLine,
+    /// Reading header lines until the empty line that ends the header section.
+    Headers,
+    /// Reading a `Content-Length` body, including the number of bytes still expected.
+    Body(usize),
+    /// Reading a `Transfer-Encoding: chunked` body.
+    Chunked,
+}
+
+/// Represents the outcome of feeding bytes into a `RequestParser`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RequestParseStatus {
+    /// All fed bytes were consumed and more input is needed to complete the request.
+    Partial,
+    /// A request was completed, including the number of bytes of the last feed it consumed.
+    /// The request is boxed so every status returned from `feed` stays small.
+    Complete(Box<Request>, usize),
+}
+
+/// Represents the outcome of feeding bytes into a `RequestParser` that stops after the headers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RequestHeadParseStatus {
+    /// All fed bytes were consumed and more input is needed to complete the headers.
+    Partial,
+    /// The request line and headers were completed, including how the body is delimited
+    /// and the number of bytes of the last feed consumed. The request body is empty.
+    Complete(Box<Request>, RequestBodyFraming, usize),
+}
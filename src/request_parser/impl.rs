@@ -0,0 +1,289 @@
+use crate::*;
+
+impl RequestParser {
+    /// Creates a new `RequestParser` that enforces the limits of the given configuration.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestConfig` - Configuration providing the request line, header and body limits.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestParser` - A parser waiting for the request line.
+    #[inline(always)]
+    pub fn new(config: RequestConfig) -> Self {
+        Self {
+            config,
+            chunked_decoder: ChunkedDecoder::new(config),
+            ..Default::default()
+        }
+    }
+
+    /// Checks whether the parser is between requests without any buffered input.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether no part of a request has been fed yet.
+    #[inline(always)]
+    pub fn is_idle(&self) -> bool {
+        self.state == RequestParserState::FirstLine && self.line.is_empty()
+    }
+
+    /// Discards any partially parsed request so the parser can start over.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        *self = Self::new(self.config);
+    }
+
+    /// Gets the maximum length of the request line.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The maximum line length in bytes.
+    #[inline(always)]
+    fn get_max_first_line_size(&self) -> usize {
+        self.config
+            .get_max_path_size()
+            .saturating_add(self.config.get_buffer_size())
+    }
+
+    /// Gets the maximum length of a header line.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The maximum line length in bytes.
+    #[inline(always)]
+    fn get_max_header_line_size(&self) -> usize {
+        self.config
+            .get_max_header_key_size()
+            .saturating_add(self.config.get_max_header_value_size())
+            .saturating_add(self.config.get_buffer_size())
+    }
+
+    /// Takes the next request or header line from the input as UTF-8, applying the
+    /// length limit of the current parsing step.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The unread input bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Option<String>, usize), RequestError>` - The completed line without
+    ///   its line ending, if any, and the number of input bytes consumed.
+    fn take_line(&mut self, input: &[u8]) -> Result<(Option<String>, usize), RequestError> {
+        let (max_size, too_long): (usize, RequestError) =
+            if self.state == RequestParserState::FirstLine {
+                (
+                    self.get_max_first_line_size(),
+                    RequestError::RequestTooLong(HttpStatus::URITooLong),
+                )
+            } else {
+                (
+                    self.get_max_header_line_size(),
+                    RequestError::HeaderLineTooLong(HttpStatus::RequestHeaderFieldsTooLarge),
+                )
+            };
+        let (line_opt, consumed): (Option<Vec<u8>>, usize) =
+            self.line.take_line(input, max_size, too_long)?;
+        let line_opt: Option<String> = line_opt
+            .map(String::from_utf8)
+            .transpose()
+            .map_err(|_| RequestError::HttpRead(HttpStatus::BadRequest))?;
+        Ok((line_opt, consumed))
+    }
+
+    /// Handles a complete request line.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the request line is valid.
+    fn parse_first_line(&mut self, line: &str) -> Result<(), RequestError> {
+        if line.is_empty() {
+            return Ok(());
+        }
+        self.request = Http::parse_request_line(line, &self.config)?;
+        self.state = RequestParserState::Headers;
+        Ok(())
+    }
+
+    /// Handles a complete header line, or the end of the header section.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<bool, RequestError>` - Whether the request is complete without a body.
+    fn parse_header_line(&mut self, line: &str) -> Result<bool, RequestError> {
+        if !Http::is_header_section_end(line, &self.config) {
+            self.header_count += 1;
+            Http::check_header_count(self.header_count, self.config.get_max_header_count())?;
+            if let Some((key, value)) = Http::parse_header_line(line, &self.config)? {
+                self.request
+                    .get_mut_headers()
+                    .entry(key)
+                    .or_default()
+                    .push_back(value);
+            }
+            return Ok(false);
+        }
+        let (host, content_size, is_chunked): (RequestHost, usize, bool) =
+            Http::parse_header_framing(self.request.get_headers(), &self.config)?;
+        Http::apply_host_header(&mut self.request, host, &self.config)?;
+        if is_chunked {
+            self.state = RequestParserState::Chunked;
+            return Ok(false);
+        }
+        if content_size > 0 {
+            self.state = RequestParserState::Body(content_size);
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Takes the assembled request and prepares the parser for the next one.
+    ///
+    /// # Returns
+    ///
+    /// - `Request` - The completed request.
+    fn take_request(&mut self) -> Request {
+        let request: Request = std::mem::take(&mut self.request);
+        self.reset();
+        request
+    }
+
+    /// Feeds bytes into the parser.
+    ///
+    /// Parsing stops as soon as a request is complete. Bytes that belong to a
+    /// following pipelined request are left unconsumed and should be fed again.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The next received bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<RequestParseStatus, RequestError>` - `Partial` if more input is needed,
+    ///   `Complete` with the request and the number of bytes consumed from this input,
+    ///   or an error if the request is malformed or exceeds the configured limits.
+    pub fn feed(&mut self, data: &[u8]) -> Result<RequestParseStatus, RequestError> {
+        let mut offset: usize = 0;
+        loop {
+            match self.state {
+                RequestParserState::FirstLine | RequestParserState::Headers => {
+                    if offset == data.len() {
+                        return Ok(RequestParseStatus::Partial);
+                    }
+                    let (line_opt, consumed): (Option<String>, usize) =
+                        self.take_line(&data[offset..])?;
+                    offset += consumed;
+                    let line: String = match line_opt {
+                        Some(line) => line,
+                        None => continue,
+                    };
+                    if self.state == RequestParserState::FirstLine {
+                        self.parse_first_line(&line)?;
+                    } else if self.parse_header_line(&line)? {
+                        return Ok(RequestParseStatus::Complete(
+                            Box::new(self.take_request()),
+                            offset,
+                        ));
+                    }
+                }
+                RequestParserState::Body(remaining) => {
+                    if offset == data.len() {
+                        return Ok(RequestParseStatus::Partial);
+                    }
+                    let size: usize = remaining.min(data.len() - offset);
+                    let body: &mut RequestBody = self.request.get_mut_body();
+                    if body.is_empty() {
+                        body.reserve(remaining.min(MAX_BODY_PREALLOCATION_SIZE));
+                    }
+                    body.extend_from_slice(&data[offset..offset + size]);
+                    offset += size;
+                    if size < remaining {
+                        self.state = RequestParserState::Body(remaining - size);
+                        continue;
+                    }
+                    return Ok(RequestParseStatus::Complete(
+                        Box::new(self.take_request()),
+                        offset,
+                    ));
+                }
+                RequestParserState::Chunked => {
+                    offset += self
+                        .chunked_decoder
+                        .decode(&data[offset..], self.request.get_mut_body())?;
+                    if !self.chunked_decoder.is_done() {
+                        return Ok(RequestParseStatus::Partial);
+                    }
+                    self.request
+                        .set_trailers(self.chunked_decoder.get_trailers().clone());
+                    return Ok(RequestParseStatus::Complete(
+                        Box::new(self.take_request()),
+                        offset,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl RequestParser {
+    /// Feeds bytes into the parser, stopping after the header section.
+    ///
+    /// The body is not read, so it can be streamed with `RequestBodyStream`.
+    /// Bytes after the header section are left unconsumed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The next received bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<RequestHeadParseStatus, RequestError>` - `Partial` if more input is needed,
+    ///   `Complete` with the request, its body framing and the number of bytes consumed
+    ///   from this input, or an error if the request head is malformed or exceeds the
+    ///   configured limits.
+    pub fn feed_head(&mut self, data: &[u8]) -> Result<RequestHeadParseStatus, RequestError> {
+        let mut offset: usize = 0;
+        let mut is_complete: bool = false;
+        while !is_complete
+            && matches!(
+                self.state,
+                RequestParserState::FirstLine | RequestParserState::Headers
+            )
+        {
+            if offset == data.len() {
+                return Ok(RequestHeadParseStatus::Partial);
+            }
+            let (line_opt, consumed): (Option<String>, usize) = self.take_line(&data[offset..])?;
+            offset += consumed;
+            let line: String = match line_opt {
+                Some(line) => line,
+                None => continue,
+            };
+            if self.state == RequestParserState::FirstLine {
+                self.parse_first_line(&line)?;
+            } else {
+                is_complete = self.parse_header_line(&line)?;
+            }
+        }
+        let framing: RequestBodyFraming = match self.state {
+            RequestParserState::Body(content_size) => RequestBodyFraming::Length(content_size),
+            RequestParserState::Chunked => RequestBodyFraming::Chunked,
+            _ => RequestBodyFraming::Empty,
+        };
+        Ok(RequestHeadParseStatus::Complete(
+            Box::new(self.take_request()),
+            framing,
+            offset,
+        ))
+    }
+}
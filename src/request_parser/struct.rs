@@ -0,0 +1,28 @@
+use crate::*;
+
+/// Incremental sans-IO parser for HTTP/1.x requests.
+///
+/// The parser does not perform any I/O. Bytes are fed through `feed` as they
+/// arrive, and a complete request is returned together with the number of
+/// bytes it consumed, so pipelined requests can be parsed from the remainder.
+#[derive(Clone, Debug, Default, Eq, Getter, PartialEq)]
+pub struct RequestParser {
+    /// Configuration providing the request line, header and body limits.
+    #[get(skip)]
+    pub(super) config: RequestConfig,
+    /// The current parsing step.
+    #[get(type(copy))]
+    pub(super) state: RequestParserState,
+    /// The partial line collected across calls to `feed`.
+    #[get(skip)]
+    pub(super) line: LineBuffer,
+    /// The request being assembled.
+    #[get(skip)]
+    pub(super) request: Request,
+    /// The number of header lines read so far.
+    #[get(skip)]
+    pub(super) header_count: usize,
+    /// The decoder used for chunked request bodies.
+    #[get(skip)]
+    pub(super) chunked_decoder: ChunkedDecoder,
+}
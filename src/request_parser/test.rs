@@ -0,0 +1,298 @@
+use crate::*;
+
+#[test]
+fn request_parser_complete_in_one_feed() {
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    let data: &[u8] =
+        b"POST /submit?name=value HTTP/1.1\r\nHost: example.com\r\nContent-Length: 5\r\n\r\nhello";
+    match parser.feed(data).unwrap() {
+        RequestParseStatus::Complete(request, consumed) => {
+            assert_eq!(consumed, data.len());
+            assert_eq!(request.get_method(), &Method::Post);
+            assert_eq!(request.get_path(), "/submit");
+            assert_eq!(request.get_host(), "example.com");
+            assert_eq!(request.get_query("name"), "value");
+            assert_eq!(request.get_body(), b"hello");
+        }
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+    assert_eq!(parser.get_state(), RequestParserState::FirstLine);
+}
+
+#[test]
+fn request_parser_byte_by_byte() {
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    let data: &[u8] =
+        b"\r\nGET /index HTTP/1.1\r\nHost: localhost\r\nX-Test: a\r\nX-Test: b\r\n\r\n";
+    for (index, byte) in data.iter().enumerate() {
+        match parser.feed(std::slice::from_ref(byte)).unwrap() {
+            RequestParseStatus::Partial => assert!(index < data.len() - 1),
+            RequestParseStatus::Complete(request, consumed) => {
+                assert_eq!(index, data.len() - 1);
+                assert_eq!(consumed, 1);
+                assert_eq!(request.get_path(), "/index");
+                assert_eq!(request.get_header_size("x-test"), 2);
+                assert!(request.get_body().is_empty());
+            }
+        }
+    }
+}
+
+#[test]
+fn request_parser_pipelined_requests() {
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    let first: &[u8] = b"POST /a HTTP/1.1\r\nContent-Length: 2\r\n\r\nok";
+    let second: &[u8] = b"GET /b HTTP/1.1\r\n\r\n";
+    let data: Vec<u8> = [first, second].concat();
+    let consumed: usize = match parser.feed(&data).unwrap() {
+        RequestParseStatus::Complete(request, consumed) => {
+            assert_eq!(request.get_path(), "/a");
+            assert_eq!(request.get_body(), b"ok");
+            consumed
+        }
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    };
+    assert_eq!(consumed, first.len());
+    match parser.feed(&data[consumed..]).unwrap() {
+        RequestParseStatus::Complete(request, consumed) => {
+            assert_eq!(request.get_path(), "/b");
+            assert_eq!(consumed, second.len());
+        }
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+}
+
+#[test]
+fn request_parser_chunked_body() {
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    let head: &[u8] = b"POST /upload HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n";
+    let tail: &[u8] = b"6\r\n world\r\n0\r\nX-Trailer: done\r\nContent-Length: 99\r\nHost: evil.com\r\nAuthorization: Basic eA==\r\n\r\n";
+    assert_eq!(parser.feed(head).unwrap(), RequestParseStatus::Partial);
+    match parser.feed(tail).unwrap() {
+        RequestParseStatus::Complete(request, consumed) => {
+            assert_eq!(consumed, tail.len());
+            assert_eq!(request.get_body(), b"hello world");
+            assert_eq!(
+                request
+                    .get_trailers()
+                    .get("x-trailer")
+                    .unwrap()
+                    .back()
+                    .unwrap(),
+                "done"
+            );
+            assert_eq!(request.get_trailers().len(), 1);
+            assert!(!request.has_header(CONTENT_LENGTH));
+            assert!(!request.has_header(HOST));
+            assert!(!request.has_header(AUTHORIZATION));
+            assert!(request.get_host().is_empty());
+        }
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+}
+
+#[test]
+fn request_parser_limits() {
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_header_count(1);
+    let mut parser: RequestParser = RequestParser::new(config);
+    let result: Result<RequestParseStatus, RequestError> =
+        parser.feed(b"GET / HTTP/1.1\r\nA: 1\r\nB: 2\r\n\r\n");
+    assert_eq!(
+        result,
+        Err(RequestError::TooManyHeaders(
+            HttpStatus::RequestHeaderFieldsTooLarge
+        ))
+    );
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_path_size(4).set_buffer_size(4);
+    let mut parser: RequestParser = RequestParser::new(config);
+    let result: Result<RequestParseStatus, RequestError> = parser.feed(b"GET /very-long-path");
+    assert_eq!(
+        result,
+        Err(RequestError::RequestTooLong(HttpStatus::URITooLong))
+    );
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    let result: Result<RequestParseStatus, RequestError> =
+        parser.feed(b"POST / HTTP/1.1\r\nContent-Length: abc\r\n\r\n");
+    assert!(result.is_err());
+}
+
+fn feed_error(config: RequestConfig, data: &[u8]) -> Option<RequestError> {
+    RequestParser::new(config).feed(data).err()
+}
+
+#[test]
+fn request_parser_rejects_smuggling_vectors() {
+    let config: RequestConfig = RequestConfig::default();
+    let bad_request: HttpStatus = HttpStatus::BadRequest;
+    assert_eq!(
+        feed_error(
+            config,
+            b"POST / HTTP/1.1\r\nContent-Length: 3\r\nTransfer-Encoding: chunked\r\n\r\n"
+        ),
+        Some(RequestError::ContentLengthWithTransferEncoding(bad_request))
+    );
+    assert_eq!(
+        feed_error(
+            config,
+            b"POST / HTTP/1.1\r\nContent-Length: 3\r\nContent-Length: 4\r\n\r\n"
+        ),
+        Some(RequestError::ConflictingContentLength(bad_request))
+    );
+    assert_eq!(
+        feed_error(config, b"POST / HTTP/1.1\r\nContent-Length: 3, 4\r\n\r\n"),
+        Some(RequestError::ConflictingContentLength(bad_request))
+    );
+    assert_eq!(
+        feed_error(config, b"POST / HTTP/1.1\r\nContent-Length: +3\r\n\r\n"),
+        Some(RequestError::InvalidContentLength(bad_request))
+    );
+    assert_eq!(
+        feed_error(
+            config,
+            b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked, gzip\r\n\r\n"
+        ),
+        Some(RequestError::InvalidTransferEncoding(bad_request))
+    );
+    assert_eq!(
+        feed_error(config, b"GET / HTTP/1.1\r\nX-A: 1\r\n  folded\r\n\r\n"),
+        Some(RequestError::ObsoleteLineFolding(bad_request))
+    );
+    assert_eq!(
+        feed_error(config, b"GET / HTTP/1.1\r\nContent-Length : 3\r\n\r\n"),
+        Some(RequestError::WhitespaceBeforeColon(bad_request))
+    );
+    assert_eq!(
+        feed_error(config, b"GET / HTTP/1.1\r\nno colon here\r\n\r\n"),
+        Some(RequestError::MissingHeaderColon(bad_request))
+    );
+    assert_eq!(
+        feed_error(config, b"GET / HTTP/1.1\r\nX[A]: 1\r\n\r\n"),
+        Some(RequestError::InvalidHeaderName(bad_request))
+    );
+}
+
+#[test]
+fn request_parser_strict_accepts_identical_content_length() {
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    let data: &[u8] = b"POST / HTTP/1.1\r\nContent-Length: 2\r\nContent-Length: 2, 2\r\n\r\nok";
+    match parser.feed(data).unwrap() {
+        RequestParseStatus::Complete(request, _) => assert_eq!(request.get_body(), b"ok"),
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+}
+
+#[test]
+fn request_parser_lenient_mode() {
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_lenient_header_parsing(true);
+    let mut parser: RequestParser = RequestParser::new(config);
+    let data: &[u8] =
+        b"POST / HTTP/1.1\r\nno colon here\r\nContent-Length : 3\r\nContent-Length: 2\r\n\r\nok";
+    match parser.feed(data).unwrap() {
+        RequestParseStatus::Complete(request, consumed) => {
+            assert_eq!(consumed, data.len());
+            assert_eq!(request.get_body(), b"ok");
+        }
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+    assert!(RequestConfig::low_security().get_lenient_header_parsing());
+    assert!(!RequestConfig::high_security().get_lenient_header_parsing());
+}
+
+fn feed_complete(config: RequestConfig, data: &[u8]) -> Request {
+    match RequestParser::new(config).feed(data).unwrap() {
+        RequestParseStatus::Complete(request, _) => *request,
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+}
+
+#[test]
+fn request_parser_request_target_forms() {
+    let config: RequestConfig = RequestConfig::default();
+    let absolute: Request = feed_complete(
+        config,
+        b"GET http://Example.com:80/x?tag=a&tag=b HTTP/1.1\r\nHost: example.com\r\n\r\n",
+    );
+    assert_eq!(absolute.get_target_form(), RequestTarget::Absolute);
+    assert_eq!(absolute.get_host(), "example.com");
+    assert_eq!(absolute.get_path(), "/x");
+    assert_eq!(absolute.get_query_values("tag").len(), 2);
+    assert_eq!(absolute.get_target(), "/x?tag=a&tag=b");
+    let connect: Request = feed_complete(
+        config,
+        b"CONNECT [::1]:443 HTTP/1.1\r\nHost: [::1]:443\r\n\r\n",
+    );
+    assert_eq!(connect.get_target_form(), RequestTarget::Authority);
+    assert_eq!(connect.get_host(), "[::1]:443");
+    assert!(connect.get_path().is_empty());
+    assert_eq!(connect.get_target(), "[::1]:443");
+    let options: Request =
+        feed_complete(config, b"OPTIONS * HTTP/1.1\r\nHost: example.com\r\n\r\n");
+    assert_eq!(options.get_target_form(), RequestTarget::Asterisk);
+    assert_eq!(options.get_host(), "example.com");
+    assert_eq!(options.get_target(), ASTERISK_REQUEST_TARGET);
+    let origin: Request = feed_complete(config, b"GET /a HTTP/1.1\r\nHost: example.com\r\n\r\n");
+    assert_eq!(origin.get_target_form(), RequestTarget::Origin);
+}
+
+#[test]
+fn request_parser_rejects_invalid_request_targets() {
+    let config: RequestConfig = RequestConfig::default();
+    let invalid: Option<RequestError> =
+        Some(RequestError::InvalidRequestTarget(HttpStatus::BadRequest));
+    for data in [
+        b"GET * HTTP/1.1\r\nHost: a\r\n\r\n".as_slice(),
+        b"CONNECT example.com HTTP/1.1\r\nHost: a\r\n\r\n",
+        b"CONNECT /path:443 HTTP/1.1\r\nHost: a\r\n\r\n",
+        b"GET ftp://example.com/x HTTP/1.1\r\nHost: a\r\n\r\n",
+        b"GET relative/path HTTP/1.1\r\nHost: a\r\n\r\n",
+    ] {
+        assert_eq!(feed_error(config, data), invalid);
+    }
+    let mismatch: &[u8] = b"GET http://example.com/x HTTP/1.1\r\nHost: evil.com\r\n\r\n";
+    assert_eq!(
+        feed_error(config, mismatch),
+        Some(RequestError::HostMismatch(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        feed_error(config, b"GET http://example.com/x HTTP/1.1\r\n\r\n"),
+        Some(RequestError::HostMismatch(HttpStatus::BadRequest))
+    );
+    for data in [
+        b"GET http://example.com/x HTTP/1.1\r\nHost: example.com:443\r\n\r\n".as_slice(),
+        b"GET https://example.com/x HTTP/1.1\r\nHost: example.com:80\r\n\r\n",
+    ] {
+        assert_eq!(
+            feed_error(config, data),
+            Some(RequestError::HostMismatch(HttpStatus::BadRequest))
+        );
+    }
+    let https: Request = feed_complete(
+        config,
+        b"GET https://example.com/x HTTP/1.1\r\nHost: example.com:443\r\n\r\n",
+    );
+    assert_eq!(https.get_scheme(), HTTPS_LOWERCASE);
+    assert_eq!(https.get_host(), "example.com");
+    let mut lenient: RequestConfig = RequestConfig::default();
+    lenient.set_lenient_header_parsing(true);
+    assert_eq!(feed_complete(lenient, mismatch).get_host(), "example.com");
+}
+
+#[test]
+fn request_parser_does_not_preallocate_declared_body() {
+    let data: &[u8] = b"POST /upload HTTP/1.1\r\nHost: a\r\nContent-Length: 500000000\r\n\r\nab";
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::low_security());
+    match parser.feed_head(data).unwrap() {
+        RequestHeadParseStatus::Complete(request, framing, consumed) => {
+            assert_eq!(framing, RequestBodyFraming::Length(500_000_000));
+            assert_eq!(consumed, data.len() - 2);
+            assert_eq!(request.get_body().capacity(), 0);
+        }
+        RequestHeadParseStatus::Partial => panic!("expected a complete request head"),
+    }
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::low_security());
+    assert_eq!(parser.feed(data).unwrap(), RequestParseStatus::Partial);
+    assert!(parser.request.get_body().capacity() <= MAX_BODY_PREALLOCATION_SIZE);
+}
@@ -16,4 +16,8 @@ pub enum ResponseError {
     Send(String),
     /// Represents a flush operation error with a message string.
     FlushError(String),
+    /// Represents a failure to read a file sent in the body, with a message string.
+    ReadFile(String),
+    /// Represents a header that cannot be written safely, with a message string.
+    InvalidHeader(String),
 }
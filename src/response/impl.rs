@@ -49,6 +49,8 @@ impl Display for ResponseError {
             }
             Self::Send(error) => write!(f, "Send error{COLON_SPACE}{error}"),
             Self::FlushError(error) => write!(f, "Flush error{COLON_SPACE}{error}"),
+            Self::ReadFile(error) => write!(f, "Read file error{COLON_SPACE}{error}"),
+            Self::InvalidHeader(error) => write!(f, "Invalid header{COLON_SPACE}{error}"),
             Self::Unknown => write!(f, "Unknown error"),
         }
     }
@@ -66,6 +68,7 @@ impl Default for Response {
             status_code: http_status.code(),
             reason_phrase: http_status.to_string(),
             headers: hash_map_xx_hash3_64(),
+            trailers: hash_map_xx_hash3_64(),
             body: Vec::new(),
         }
     }
@@ -662,3 +665,183 @@ impl Response {
         response_bytes
     }
 }
+
+impl Response {
+    /// Builds the status line and headers of a response whose body is sent in chunks.
+    ///
+    /// `Transfer-Encoding: chunked` replaces any `Content-Length`, and the body set on the
+    /// response is ignored. The chunks are written with `ChunkedResponseWriter`.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseData` - The response head bytes, ending with the empty line.
+    pub fn build_chunked_head(&mut self) -> ResponseData {
+        if self.reason_phrase.is_empty() {
+            self.set_reason_phrase(HttpStatus::phrase(self.get_status_code()));
+        }
+        let mut response_string: String = String::with_capacity(DEFAULT_BUFFER_SIZE);
+        self.push_http_first_line(&mut response_string);
+        if self.try_get_header_back(CONNECTION).is_none() {
+            self.set_header_without_check(CONNECTION, KEEP_ALIVE);
+        }
+        self.headers.remove(CONTENT_LENGTH);
+        self.set_header_without_check(TRANSFER_ENCODING, CHUNKED);
+        self.get_headers().iter().for_each(|(key, values)| {
+            for value in values.iter() {
+                Self::push_header(&mut response_string, key, value);
+            }
+        });
+        response_string.push_str(HTTP_BR);
+        response_string.into_bytes()
+    }
+}
+
+impl Response {
+    /// Parses the body of a `text/event-stream` response into Server-Sent Events.
+    ///
+    /// An event that is not terminated by an empty line is not returned.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<SseEvent>` - The events in the body.
+    #[inline(always)]
+    pub fn get_sse_events(&self) -> Vec<SseEvent> {
+        SseParser::default().feed(&self.body)
+    }
+
+    /// Checks whether a parsed response is an interim response that precedes the final one.
+    ///
+    /// `101 Switching Protocols` is final, because the connection changes protocol after it.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the response has a 1xx status other than 101.
+    #[inline(always)]
+    fn is_interim(&self) -> bool {
+        (100..200).contains(&self.status_code)
+            && self.status_code != HttpStatus::SwitchingProtocols.code()
+    }
+
+    /// Parses a complete HTTP response from bytes.
+    ///
+    /// Interim 1xx responses are skipped. A body without Content-Length or chunked
+    /// encoding extends to the end of the data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The received response bytes.
+    /// - `&RequestMethod` - The method of the request being answered.
+    /// - `&RequestConfig` - Configuration for header and body limits.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Response, RequestError>` - The parsed response or an error if the data is
+    ///   malformed, incomplete or exceeds the configured limits.
+    pub fn from_bytes(
+        data: &[u8],
+        method: &RequestMethod,
+        config: &RequestConfig,
+    ) -> Result<Response, RequestError> {
+        let mut parser: ResponseParser = ResponseParser::new(method, *config);
+        let mut offset: usize = 0;
+        loop {
+            match parser.feed(&data[offset..])? {
+                ResponseParseStatus::Complete(response, consumed) => {
+                    offset += consumed;
+                    if !response.is_interim() {
+                        return Ok(response);
+                    }
+                }
+                ResponseParseStatus::Partial => {
+                    return parser
+                        .finish()?
+                        .ok_or(RequestError::ReadConnection(HttpStatus::BadGateway));
+                }
+            }
+        }
+    }
+
+    /// Reads responses from the buffer and stream until a final one is complete.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream<R, W>` - The stream to read from.
+    /// - `&mut Vec<u8>` - Bytes already read from the stream, left holding the bytes
+    ///   that follow the response.
+    /// - `&RequestMethod` - The method of the request being answered.
+    /// - `&RequestConfig` - Configuration for header and body limits.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Response, RequestError>` - The final response or an error.
+    pub(crate) async fn read_from_stream<R, W>(
+        stream: &ArcRwLockStream<R, W>,
+        buffer: &mut Vec<u8>,
+        method: &RequestMethod,
+        config: &RequestConfig,
+    ) -> Result<Response, RequestError>
+    where
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
+    {
+        let mut parser: ResponseParser = ResponseParser::new(method, *config);
+        loop {
+            if !buffer.is_empty() {
+                match parser.feed(buffer)? {
+                    ResponseParseStatus::Complete(response, consumed) => {
+                        buffer.drain(..consumed);
+                        if !response.is_interim() {
+                            return Ok(response);
+                        }
+                        continue;
+                    }
+                    ResponseParseStatus::Partial => buffer.clear(),
+                }
+            }
+            buffer.reserve(config.get_buffer_size());
+            let size: usize = stream.read_mut().await.read_buf(buffer).await?;
+            if size == 0 {
+                return parser
+                    .finish()?
+                    .ok_or(RequestError::ReadConnection(HttpStatus::BadGateway));
+            }
+        }
+    }
+
+    /// Parses an HTTP response from a stream.
+    ///
+    /// Interim 1xx responses are skipped. A body without Content-Length or chunked
+    /// encoding is read until the server closes the connection.
+    /// If the timeout is DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS, no timeout is applied.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockStream<R, W>` - The stream to read from.
+    /// - `&RequestMethod` - The method of the request being answered.
+    /// - `&RequestConfig` - Configuration for header and body limits and the read timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Response, RequestError>` - The parsed response or an error.
+    pub async fn from_stream<R, W>(
+        stream: &ArcRwLockStream<R, W>,
+        method: &RequestMethod,
+        config: &RequestConfig,
+    ) -> Result<Response, RequestError>
+    where
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
+    {
+        let mut buffer: Vec<u8> = Vec::with_capacity(config.get_buffer_size());
+        let timeout_ms: u64 = config.get_read_timeout_ms();
+        if timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
+            return Self::read_from_stream(stream, &mut buffer, method, config).await;
+        }
+        let duration: Duration = Duration::from_millis(timeout_ms);
+        timeout(
+            duration,
+            Self::read_from_stream(stream, &mut buffer, method, config),
+        )
+        .await?
+    }
+}
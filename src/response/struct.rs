@@ -15,6 +15,8 @@ pub struct Response {
     pub(super) reason_phrase: ResponseReasonPhrase,
     /// The response headers as key-value pairs.
     pub(super) headers: ResponseHeaders,
+    /// Trailer fields received after a chunked body, kept apart from the headers.
+    pub(super) trailers: ResponseHeaders,
     /// The binary body content of the response.
     #[set(type(AsRef<[u8]>))]
     pub(super) body: ResponseBody,
@@ -0,0 +1,26 @@
+use crate::*;
+
+/// Represents the parsing step of an incremental HTTP/1.x response parser.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ResponseParserState {
+    /// Waiting for the status line.
+    #[default]
+    StatusLine,
+    /// Reading header lines until the empty line that ends the header section.
+    Headers,
+    /// Reading a `Content-Length` body, including the number of bytes still expected.
+    Body(usize),
+    /// Reading a `Transfer-Encoding: chunked` body.
+    Chunked,
+    /// Reading a body that ends when the connection is closed.
+    UntilClose,
+}
+
+/// Represents the outcome of feeding bytes into a `ResponseParser`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResponseParseStatus {
+    /// All fed bytes were consumed and more input is needed to complete the response.
+    Partial,
+    /// A response was completed, including the number of bytes of the last feed it consumed.
+    Complete(Response, usize),
+}
@@ -0,0 +1,307 @@
+use crate::*;
+
+impl ResponseParser {
+    /// Creates a new `ResponseParser` for the response to a request with the given method.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestMethod` - The method of the request being answered.
+    /// - `RequestConfig` - Configuration providing the header and body limits.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseParser` - A parser waiting for the status line.
+    #[inline(always)]
+    pub fn new(method: &RequestMethod, config: RequestConfig) -> Self {
+        Self {
+            config,
+            is_head_request: method.is_head(),
+            chunked_decoder: ChunkedDecoder::new(config),
+            ..Default::default()
+        }
+    }
+
+    /// Checks whether the parser is between responses without any buffered input.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether no part of a response has been fed yet.
+    #[inline(always)]
+    pub fn is_idle(&self) -> bool {
+        self.state == ResponseParserState::StatusLine && self.line.is_empty()
+    }
+
+    /// Discards any partially parsed response so the parser can start over.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        let is_head_request: bool = self.is_head_request;
+        *self = Self {
+            config: self.config,
+            is_head_request,
+            chunked_decoder: ChunkedDecoder::new(self.config),
+            ..Default::default()
+        };
+    }
+
+    /// Gets the maximum length of the status line or a header line.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The maximum line length in bytes.
+    #[inline(always)]
+    fn get_max_line_size(&self) -> usize {
+        self.config
+            .get_max_header_key_size()
+            .saturating_add(self.config.get_max_header_value_size())
+            .saturating_add(self.config.get_buffer_size())
+    }
+
+    /// Takes the next status or header line from the input as UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The unread input bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Option<String>, usize), RequestError>` - The completed line without
+    ///   its line ending, if any, and the number of input bytes consumed.
+    fn take_line(&mut self, input: &[u8]) -> Result<(Option<String>, usize), RequestError> {
+        let (line_opt, consumed): (Option<Vec<u8>>, usize) = self.line.take_line(
+            input,
+            self.get_max_line_size(),
+            RequestError::HeaderLineTooLong(HttpStatus::RequestHeaderFieldsTooLarge),
+        )?;
+        let line_opt: Option<String> = line_opt
+            .map(String::from_utf8)
+            .transpose()
+            .map_err(|_| RequestError::InvalidStatusLine(HttpStatus::BadGateway))?;
+        Ok((line_opt, consumed))
+    }
+
+    /// Handles a complete status line.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The status line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the status line is valid.
+    fn parse_status_line(&mut self, line: &str) -> Result<(), RequestError> {
+        let invalid: RequestError = RequestError::InvalidStatusLine(HttpStatus::BadGateway);
+        let (version, rest): (&str, &str) = line.split_once(SPACE).ok_or(invalid.clone())?;
+        let (status_code, reason_phrase): (&str, &str) =
+            rest.split_once(SPACE).unwrap_or((rest, EMPTY_STR));
+        if !version.starts_with(HTTP_UPPERCASE)
+            || status_code.len() != 3
+            || !status_code.bytes().all(|byte: u8| byte.is_ascii_digit())
+        {
+            return Err(invalid);
+        }
+        self.response.set_version(
+            version
+                .parse::<ResponseVersion>()
+                .unwrap_or_else(|_| ResponseVersion::Unknown(version.to_string())),
+        );
+        self.response.set_status_code(
+            status_code
+                .parse::<ResponseStatusCode>()
+                .map_err(|_| invalid)?,
+        );
+        self.response.set_reason_phrase(reason_phrase);
+        self.state = ResponseParserState::Headers;
+        Ok(())
+    }
+
+    /// Determines how the body of the response is delimited.
+    ///
+    /// Responses to HEAD requests and responses with a 1xx, 204 or 304 status never have
+    /// a body. Otherwise chunked transfer encoding takes precedence, then Content-Length,
+    /// and without either the body lasts until the connection is closed.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<ResponseParserState>, RequestError>` - The state reading the body,
+    ///   `None` if the response has no body, or an error if the framing is invalid.
+    fn get_body_state(&self) -> Result<Option<ResponseParserState>, RequestError> {
+        let status_code: ResponseStatusCode = self.response.get_status_code();
+        if self.is_head_request
+            || (100..200).contains(&status_code)
+            || status_code == HttpStatus::NoContent.code()
+            || status_code == HttpStatus::NotModified.code()
+        {
+            return Ok(None);
+        }
+        let headers: &ResponseHeaders = self.response.get_headers();
+        let is_lenient: bool = self.config.get_lenient_header_parsing();
+        let max_body_size: usize = self.config.get_max_body_size();
+        if let Some(values) = headers.get(TRANSFER_ENCODING) {
+            if !is_lenient && headers.contains_key(CONTENT_LENGTH) {
+                return Err(RequestError::ContentLengthWithTransferEncoding(
+                    HttpStatus::BadGateway,
+                ));
+            }
+            let is_chunked: bool = values
+                .back()
+                .is_some_and(|value: &String| Http::is_chunked_transfer_encoding(value));
+            return Ok(Some(if is_chunked {
+                ResponseParserState::Chunked
+            } else {
+                ResponseParserState::UntilClose
+            }));
+        }
+        let content_size: usize = match headers.get(CONTENT_LENGTH) {
+            Some(values) if is_lenient => match values.back() {
+                Some(value) => Http::check_body_size(value.trim(), max_body_size)?,
+                None => 0,
+            },
+            Some(values) => Http::parse_content_length_strict(values, max_body_size)?,
+            None => return Ok(Some(ResponseParserState::UntilClose)),
+        };
+        if content_size == 0 {
+            return Ok(None);
+        }
+        Ok(Some(ResponseParserState::Body(content_size)))
+    }
+
+    /// Handles a complete header line, or the end of the header section.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<bool, RequestError>` - Whether the response is complete without a body.
+    fn parse_header_line(&mut self, line: &str) -> Result<bool, RequestError> {
+        if !Http::is_header_section_end(line, &self.config) {
+            self.header_count += 1;
+            Http::check_header_count(self.header_count, self.config.get_max_header_count())?;
+            if let Some((key, value)) = Http::parse_header_line(line, &self.config)? {
+                self.response
+                    .get_mut_headers()
+                    .entry(key)
+                    .or_default()
+                    .push_back(value);
+            }
+            return Ok(false);
+        }
+        match self.get_body_state()? {
+            Some(state) => {
+                if let ResponseParserState::Body(content_size) = state {
+                    self.response.set_body(Vec::with_capacity(
+                        content_size.min(MAX_BODY_PREALLOCATION_SIZE),
+                    ));
+                }
+                self.state = state;
+                Ok(false)
+            }
+            None => Ok(true),
+        }
+    }
+
+    /// Takes the assembled response and prepares the parser for the next one.
+    ///
+    /// # Returns
+    ///
+    /// - `Response` - The completed response.
+    fn take_response(&mut self) -> Response {
+        let response: Response = std::mem::take(&mut self.response);
+        self.reset();
+        response
+    }
+
+    /// Feeds bytes into the parser.
+    ///
+    /// Parsing stops as soon as a response is complete. Bytes that follow it are left
+    /// unconsumed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The next received bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ResponseParseStatus, RequestError>` - `Partial` if more input is needed,
+    ///   `Complete` with the response and the number of bytes consumed from this input,
+    ///   or an error if the response is malformed or exceeds the configured limits.
+    pub fn feed(&mut self, data: &[u8]) -> Result<ResponseParseStatus, RequestError> {
+        let mut offset: usize = 0;
+        loop {
+            match self.state {
+                ResponseParserState::StatusLine | ResponseParserState::Headers => {
+                    if offset == data.len() {
+                        return Ok(ResponseParseStatus::Partial);
+                    }
+                    let (line_opt, consumed): (Option<String>, usize) =
+                        self.take_line(&data[offset..])?;
+                    offset += consumed;
+                    let line: String = match line_opt {
+                        Some(line) => line,
+                        None => continue,
+                    };
+                    if self.state == ResponseParserState::StatusLine {
+                        self.parse_status_line(&line)?;
+                    } else if self.parse_header_line(&line)? {
+                        return Ok(ResponseParseStatus::Complete(self.take_response(), offset));
+                    }
+                }
+                ResponseParserState::Body(remaining) => {
+                    if offset == data.len() {
+                        return Ok(ResponseParseStatus::Partial);
+                    }
+                    let size: usize = remaining.min(data.len() - offset);
+                    self.response
+                        .get_mut_body()
+                        .extend_from_slice(&data[offset..offset + size]);
+                    offset += size;
+                    if size < remaining {
+                        self.state = ResponseParserState::Body(remaining - size);
+                        continue;
+                    }
+                    return Ok(ResponseParseStatus::Complete(self.take_response(), offset));
+                }
+                ResponseParserState::Chunked => {
+                    offset += self
+                        .chunked_decoder
+                        .decode(&data[offset..], self.response.get_mut_body())?;
+                    if !self.chunked_decoder.is_done() {
+                        return Ok(ResponseParseStatus::Partial);
+                    }
+                    self.response
+                        .set_trailers(self.chunked_decoder.get_trailers().clone());
+                    return Ok(ResponseParseStatus::Complete(self.take_response(), offset));
+                }
+                ResponseParserState::UntilClose => {
+                    let max_body_size: usize = self.config.get_max_body_size();
+                    let body: &mut ResponseBody = self.response.get_mut_body();
+                    if body.len().saturating_add(data.len() - offset) > max_body_size
+                        && max_body_size != DEFAULT_LOW_SECURITY_MAX_BODY_SIZE
+                    {
+                        return Err(RequestError::ContentLengthTooLarge(
+                            HttpStatus::PayloadTooLarge,
+                        ));
+                    }
+                    body.extend_from_slice(&data[offset..]);
+                    return Ok(ResponseParseStatus::Partial);
+                }
+            }
+        }
+    }
+
+    /// Signals that the connection was closed by the peer.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Response>, RequestError>` - The response whose body lasted until
+    ///   the close, `None` if no response was started, or an error if the response was
+    ///   cut off.
+    pub fn finish(&mut self) -> Result<Option<Response>, RequestError> {
+        match self.state {
+            ResponseParserState::UntilClose => Ok(Some(self.take_response())),
+            _ if self.is_idle() => Ok(None),
+            _ => Err(RequestError::ReadConnection(HttpStatus::BadGateway)),
+        }
+    }
+}
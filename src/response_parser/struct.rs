@@ -0,0 +1,31 @@
+use crate::*;
+
+/// Incremental sans-IO parser for HTTP/1.x responses.
+///
+/// The parser does not perform any I/O. Bytes are fed through `feed` as they
+/// arrive. Because a response body may be delimited by the end of the
+/// connection, `finish` must be called once the peer has closed it.
+#[derive(Clone, Debug, Default, Eq, Getter, PartialEq)]
+pub struct ResponseParser {
+    /// Configuration providing the header and body limits.
+    #[get(skip)]
+    pub(super) config: RequestConfig,
+    /// Whether the response answers a HEAD request and therefore has no body.
+    #[get(type(copy))]
+    pub(super) is_head_request: bool,
+    /// The current parsing step.
+    #[get(type(copy))]
+    pub(super) state: ResponseParserState,
+    /// The partial line collected across calls to `feed`.
+    #[get(skip)]
+    pub(super) line: LineBuffer,
+    /// The response being assembled.
+    #[get(skip)]
+    pub(super) response: Response,
+    /// The number of header lines read so far.
+    #[get(skip)]
+    pub(super) header_count: usize,
+    /// The decoder used for chunked response bodies.
+    #[get(skip)]
+    pub(super) chunked_decoder: ChunkedDecoder,
+}
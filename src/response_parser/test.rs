@@ -0,0 +1,153 @@
+use crate::*;
+
+use tokio::io::{DuplexStream, duplex};
+
+#[test]
+fn response_parser_content_length_byte_by_byte() {
+    let mut parser: ResponseParser = ResponseParser::new(&Method::Get, RequestConfig::default());
+    let data: &[u8] = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\nX-Test: a\r\n\r\nhello";
+    for (index, byte) in data.iter().enumerate() {
+        match parser.feed(std::slice::from_ref(byte)).unwrap() {
+            ResponseParseStatus::Partial => assert!(index < data.len() - 1),
+            ResponseParseStatus::Complete(response, consumed) => {
+                assert_eq!(index, data.len() - 1);
+                assert_eq!(consumed, 1);
+                assert_eq!(response.get_status_code(), 200);
+                assert_eq!(response.get_reason_phrase(), "OK");
+                assert_eq!(response.get_version(), &HttpVersion::Http1_1);
+                assert_eq!(response.get_header_back("x-test"), "a");
+                assert_eq!(response.get_body(), b"hello");
+            }
+        }
+    }
+    assert_eq!(parser.get_state(), ResponseParserState::StatusLine);
+}
+
+#[test]
+fn response_from_bytes_chunked_with_trailers() {
+    let data: &[u8] = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\nX-Checksum: abc\r\nContent-Length: 1\r\nSet-Cookie: a=b\r\n\r\n";
+    let response: Response =
+        Response::from_bytes(data, &Method::Get, &RequestConfig::default()).unwrap();
+    assert_eq!(response.get_body(), b"hello world");
+    assert_eq!(
+        response
+            .get_trailers()
+            .get("x-checksum")
+            .unwrap()
+            .back()
+            .unwrap(),
+        "abc"
+    );
+    assert_eq!(response.get_trailers().len(), 1);
+    assert!(!response.has_header("x-checksum"));
+    assert!(!response.has_header(CONTENT_LENGTH));
+    assert!(!response.has_header(SET_COOKIE));
+}
+
+#[test]
+fn response_from_bytes_until_close() {
+    let data: &[u8] = b"HTTP/1.0 200 OK\r\nContent-Type: text/plain\r\n\r\nall the rest";
+    let response: Response =
+        Response::from_bytes(data, &Method::Get, &RequestConfig::default()).unwrap();
+    assert_eq!(response.get_version(), &HttpVersion::Http1_0);
+    assert_eq!(response.get_body(), b"all the rest");
+    let mut config: RequestConfig = RequestConfig::default();
+    config.set_max_body_size(4);
+    assert_eq!(
+        Response::from_bytes(data, &Method::Get, &config),
+        Err(RequestError::ContentLengthTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+}
+
+#[test]
+fn response_from_bytes_no_body_rules() {
+    let config: RequestConfig = RequestConfig::default();
+    let head: Response = Response::from_bytes(
+        b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n",
+        &Method::Head,
+        &config,
+    )
+    .unwrap();
+    assert!(head.get_body().is_empty());
+    for status in ["204 No Content", "304 Not Modified"] {
+        let data: String = format!("HTTP/1.1 {status}\r\n\r\n");
+        let response: Response =
+            Response::from_bytes(data.as_bytes(), &Method::Get, &config).unwrap();
+        assert!(response.get_body().is_empty());
+    }
+    let data: &[u8] =
+        b"HTTP/1.1 100 Continue\r\n\r\nHTTP/1.1 201 Created\r\nContent-Length: 2\r\n\r\nok";
+    let response: Response = Response::from_bytes(data, &Method::Post, &config).unwrap();
+    assert_eq!(response.get_status_code(), 201);
+    assert_eq!(response.get_body(), b"ok");
+}
+
+#[test]
+fn response_from_bytes_rejects_invalid_framing() {
+    let config: RequestConfig = RequestConfig::default();
+    assert_eq!(
+        Response::from_bytes(b"HTTP/1.1 OK\r\n\r\n", &Method::Get, &config),
+        Err(RequestError::InvalidStatusLine(HttpStatus::BadGateway))
+    );
+    assert_eq!(
+        Response::from_bytes(
+            b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nTransfer-Encoding: chunked\r\n\r\n",
+            &Method::Get,
+            &config
+        ),
+        Err(RequestError::ContentLengthWithTransferEncoding(
+            HttpStatus::BadGateway
+        ))
+    );
+    assert_eq!(
+        Response::from_bytes(
+            b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nshort",
+            &Method::Get,
+            &config
+        ),
+        Err(RequestError::ReadConnection(HttpStatus::BadGateway))
+    );
+}
+
+#[tokio::test]
+async fn response_from_stream() {
+    let (client, mut server): (DuplexStream, DuplexStream) = duplex(1024);
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    tokio::spawn(async move {
+        server
+            .write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n")
+            .await
+            .unwrap();
+        server.write_all(b"0\r\n\r\n").await.unwrap();
+    });
+    let response: Response =
+        Response::from_stream(&stream, &Method::Get, &RequestConfig::default())
+            .await
+            .unwrap();
+    assert_eq!(response.get_body(), b"abc");
+}
+
+#[test]
+fn response_parser_does_not_preallocate_declared_body() {
+    let mut parser: ResponseParser =
+        ResponseParser::new(&Method::Get, RequestConfig::low_security());
+    assert_eq!(
+        parser
+            .feed(b"HTTP/1.1 200 OK\r\nContent-Length: 18446744073709551615\r\n\r\nab")
+            .unwrap(),
+        ResponseParseStatus::Partial
+    );
+    assert_eq!(parser.response.get_body(), b"ab");
+    assert!(parser.response.get_body().capacity() <= MAX_BODY_PREALLOCATION_SIZE);
+    let mut parser: ResponseParser =
+        ResponseParser::new(&Method::Get, RequestConfig::low_security());
+    assert_eq!(
+        parser
+            .feed(b"HTTP/1.1 200 OK\r\nContent-Length: 500000000\r\n\r\n")
+            .unwrap(),
+        ResponseParseStatus::Partial
+    );
+    assert!(parser.response.get_body().capacity() <= MAX_BODY_PREALLOCATION_SIZE);
+}
@@ -0,0 +1,98 @@
+use crate::*;
+
+impl<R, W> ChunkedResponseWriter<R, W>
+where
+    R: AsyncReadHalf,
+    W: AsyncWriteHalf,
+{
+    /// Sends the response head and creates a writer for its body.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The stream to write to.
+    /// - `&mut Response` - The response whose status line and headers are sent. Its
+    ///   `Content-Length` is replaced with `Transfer-Encoding: chunked`.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ChunkedResponseWriter<R, W>, ResponseError>` - The writer, or an error if
+    ///   the head could not be sent.
+    pub async fn start(
+        stream: ArcRwLockStream<R, W>,
+        response: &mut Response,
+    ) -> Result<Self, ResponseError> {
+        stream.try_send(response.build_chunked_head()).await?;
+        stream.try_flush().await?;
+        Ok(Self {
+            stream,
+            sent_size: 0,
+            finished: false,
+        })
+    }
+
+    /// Sends data as one chunk and flushes it.
+    ///
+    /// Empty data is ignored, because an empty chunk would end the body.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The chunk data.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the chunk was sent, or an error if the write
+    ///   failed or the body was already finished.
+    pub async fn send_chunk<D>(&mut self, data: D) -> Result<(), ResponseError>
+    where
+        D: AsRef<[u8]>,
+    {
+        if self.finished {
+            return Err(ResponseError::Terminated);
+        }
+        let data: &[u8] = data.as_ref();
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.stream
+            .try_send(ChunkedEncoder::encode_chunk(data))
+            .await?;
+        self.stream.try_flush().await?;
+        self.sent_size += data.len();
+        Ok(())
+    }
+
+    /// Ends the body with trailer headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ResponseHeaders` - The trailer headers sent after the last chunk.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the final chunk was sent, or an error if the
+    ///   write failed or the body was already finished.
+    pub async fn finish_with_trailers(
+        &mut self,
+        trailers: &ResponseHeaders,
+    ) -> Result<(), ResponseError> {
+        if self.finished {
+            return Err(ResponseError::Terminated);
+        }
+        self.finished = true;
+        self.stream
+            .try_send(ChunkedEncoder::encode_last_chunk(trailers))
+            .await?;
+        self.stream.try_flush().await
+    }
+
+    /// Ends the body without trailer headers.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the final chunk was sent, or an error if the
+    ///   write failed or the body was already finished.
+    #[inline(always)]
+    pub async fn finish(&mut self) -> Result<(), ResponseError> {
+        self.finish_with_trailers(&hash_map_xx_hash3_64()).await
+    }
+}
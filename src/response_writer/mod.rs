@@ -0,0 +1,6 @@
+mod r#impl;
+mod r#struct;
+#[cfg(test)]
+mod test;
+
+pub use r#struct::*;
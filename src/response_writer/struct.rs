@@ -0,0 +1,19 @@
+use crate::*;
+
+/// Writer that streams a response body with `Transfer-Encoding: chunked` framing.
+///
+/// The status line and headers are sent when the writer is started. Each body
+/// chunk is framed and flushed as it is sent, and `finish` writes the final
+/// zero-size chunk with optional trailers.
+#[derive(Debug, Getter)]
+pub struct ChunkedResponseWriter<R, W> {
+    /// The stream the response is written to.
+    #[get(skip)]
+    pub(super) stream: ArcRwLockStream<R, W>,
+    /// The number of body bytes sent so far, excluding chunk framing.
+    #[get(type(copy))]
+    pub(super) sent_size: usize,
+    /// Whether the final chunk has been sent.
+    #[get(type(copy))]
+    pub(super) finished: bool,
+}
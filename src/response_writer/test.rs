@@ -0,0 +1,63 @@
+use crate::*;
+
+use tokio::io::{DuplexStream, duplex};
+
+#[tokio::test]
+async fn chunked_response_writer_round_trip() {
+    let (client, server): (DuplexStream, DuplexStream) = duplex(1024);
+    let client_stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    let server_stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(server);
+    let mut response: Response = Response::default();
+    response
+        .set_header(CONTENT_TYPE, TEXT_PLAIN)
+        .set_body(b"ignored");
+    let mut writer: ChunkedResponseWriter<_, _> =
+        ChunkedResponseWriter::start(server_stream, &mut response)
+            .await
+            .unwrap();
+    writer.send_chunk("hello ").await.unwrap();
+    writer.send_chunk(b"").await.unwrap();
+    writer.send_chunk(vec![b'x'; 300]).await.unwrap();
+    let mut trailers: ResponseHeaders = hash_map_xx_hash3_64();
+    trailers
+        .entry("x-checksum".to_string())
+        .or_default()
+        .push_back("abc".to_string());
+    writer.finish_with_trailers(&trailers).await.unwrap();
+    assert_eq!(writer.get_sent_size(), 306);
+    assert_eq!(
+        writer.send_chunk("late").await,
+        Err(ResponseError::Terminated)
+    );
+    let parsed: Response =
+        Response::from_stream(&client_stream, &Method::Get, &RequestConfig::default())
+            .await
+            .unwrap();
+    assert_eq!(parsed.get_status_code(), 200);
+    assert_eq!(parsed.get_header_back(TRANSFER_ENCODING), CHUNKED);
+    assert!(!parsed.has_header(CONTENT_LENGTH));
+    assert_eq!(&parsed.get_body()[..6], b"hello ");
+    assert_eq!(parsed.get_body().len(), 306);
+    assert_eq!(
+        parsed
+            .get_trailers()
+            .get("x-checksum")
+            .unwrap()
+            .back()
+            .unwrap(),
+        "abc"
+    );
+}
+
+#[test]
+fn chunked_encoder() {
+    assert_eq!(
+        ChunkedEncoder::encode_chunk(&[b'a'; 26]),
+        [b"1a\r\n".as_slice(), &[b'a'; 26], b"\r\n"].concat()
+    );
+    assert!(ChunkedEncoder::encode_chunk(b"").is_empty());
+    assert_eq!(
+        ChunkedEncoder::encode_last_chunk(&hash_map_xx_hash3_64()),
+        b"0\r\n\r\n"
+    );
+}
@@ -0,0 +1,20 @@
+/// The header a reconnecting client uses to send the id of the last event it received.
+pub const LAST_EVENT_ID: &str = "last-event-id";
+
+/// The Server-Sent Events field carrying the event id.
+pub const SSE_ID_FIELD: &str = "id";
+
+/// The Server-Sent Events field carrying the event type.
+pub const SSE_EVENT_FIELD: &str = "event";
+
+/// The Server-Sent Events field carrying one line of event data.
+pub const SSE_DATA_FIELD: &str = "data";
+
+/// The Server-Sent Events field carrying the reconnection time in milliseconds.
+pub const SSE_RETRY_FIELD: &str = "retry";
+
+/// The comment sent to keep an idle Server-Sent Events stream alive.
+pub const SSE_KEEP_ALIVE_COMMENT: &str = ": keep-alive\n\n";
+
+/// Default time without events before a keep-alive comment is sent, in milliseconds.
+pub const DEFAULT_SSE_KEEP_ALIVE_INTERVAL_MS: u64 = 15_000;
@@ -0,0 +1,274 @@
+use crate::*;
+
+impl SseEvent {
+    /// Creates an event carrying only data.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The event data, which may span several lines.
+    ///
+    /// # Returns
+    ///
+    /// - `SseEvent` - An event of the default `message` type.
+    #[inline(always)]
+    pub fn from_data<D>(data: D) -> Self
+    where
+        D: AsRef<str>,
+    {
+        Self {
+            data: data.as_ref().to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Appends one field line to the encoded event.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut String` - The encoded event.
+    /// - `&str` - The field name.
+    /// - `&str` - The field value, which must not contain line breaks.
+    #[inline(always)]
+    fn push_field(event_string: &mut String, field: &str, value: &str) {
+        event_string.push_str(field);
+        event_string.push_str(COLON_SPACE);
+        event_string.push_str(value);
+        event_string.push_str(BR);
+    }
+
+    /// Encodes the event in the `text/event-stream` format.
+    ///
+    /// Line breaks in the id and event type are removed. Each line of the data is sent
+    /// as a separate `data` field, and the event ends with an empty line.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The encoded event.
+    pub fn build(&self) -> Vec<u8> {
+        let mut event_string: String = String::with_capacity(self.data.len() + DEFAULT_BUFFER_SIZE);
+        let strip_line_breaks = |value: &str| -> String { value.replace(['\r', '\n'], EMPTY_STR) };
+        if let Some(id) = &self.id {
+            Self::push_field(&mut event_string, SSE_ID_FIELD, &strip_line_breaks(id));
+        }
+        if let Some(event) = &self.event {
+            Self::push_field(
+                &mut event_string,
+                SSE_EVENT_FIELD,
+                &strip_line_breaks(event),
+            );
+        }
+        if let Some(retry) = self.retry {
+            Self::push_field(&mut event_string, SSE_RETRY_FIELD, &retry.to_string());
+        }
+        let data: String = self.data.replace(HTTP_BR, BR).replace('\r', BR);
+        for line in data.split(BR) {
+            Self::push_field(&mut event_string, SSE_DATA_FIELD, line);
+        }
+        event_string.push_str(BR);
+        event_string.into_bytes()
+    }
+}
+
+impl SseParser {
+    /// Creates a new `SseParser`.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<String>` - The last event id known from a previous connection, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `SseParser` - A parser waiting for the first event.
+    #[inline(always)]
+    pub fn new(last_event_id: Option<String>) -> Self {
+        Self {
+            last_event_id,
+            ..Default::default()
+        }
+    }
+
+    /// Handles one complete line of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The line without its line ending.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SseEvent>` - The dispatched event if the line ended one.
+    fn parse_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            let event: Option<String> = self.event.take();
+            let retry: Option<u64> = self.retry.take();
+            if !std::mem::take(&mut self.has_data) {
+                return None;
+            }
+            return Some(SseEvent {
+                id: self.last_event_id.clone(),
+                event,
+                data: std::mem::take(&mut self.data),
+                retry,
+            });
+        }
+        if line.starts_with(COLON) {
+            return None;
+        }
+        let (field, value): (&str, &str) = match line.split_once(COLON) {
+            Some((field, value)) => (field, value.strip_prefix(SPACE).unwrap_or(value)),
+            None => (line, EMPTY_STR),
+        };
+        match field {
+            SSE_DATA_FIELD => {
+                if self.has_data {
+                    self.data.push_str(BR);
+                }
+                self.data.push_str(value);
+                self.has_data = true;
+            }
+            SSE_EVENT_FIELD => self.event = Some(value.to_string()),
+            SSE_ID_FIELD if !value.contains('\0') => {
+                self.last_event_id = (!value.is_empty()).then(|| value.to_string());
+            }
+            SSE_RETRY_FIELD
+                if !value.is_empty() && value.bytes().all(|byte: u8| byte.is_ascii_digit()) =>
+            {
+                self.retry = value.parse::<u64>().ok();
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Feeds bytes of the stream into the parser.
+    ///
+    /// Lines may end with CRLF, LF or CR. Comment lines and unknown fields are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The next received bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<SseEvent>` - The events completed by these bytes.
+    pub fn feed(&mut self, data: &[u8]) -> Vec<SseEvent> {
+        let mut events: Vec<SseEvent> = Vec::new();
+        for byte in data.iter().copied() {
+            if std::mem::take(&mut self.after_carriage_return) && byte == BR_U8 {
+                continue;
+            }
+            if byte != BR_U8 && byte != b'\r' {
+                self.line.push(byte);
+                continue;
+            }
+            self.after_carriage_return = byte == b'\r';
+            let line: Vec<u8> = std::mem::take(&mut self.line);
+            if let Some(event) = self.parse_line(&String::from_utf8_lossy(&line)) {
+                events.push(event);
+            }
+        }
+        events
+    }
+}
+
+impl<R, W> SseWriter<R, W>
+where
+    R: AsyncReadHalf,
+    W: AsyncWriteHalf,
+{
+    /// Sends the response head and creates a writer for the event stream.
+    ///
+    /// The response is sent as `text/event-stream` with caching disabled and its body
+    /// is discarded.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The stream to write to.
+    /// - `&mut Response` - The response whose status line and headers are sent.
+    /// - `u64` - Time without events before a keep-alive comment is sent, in
+    ///   milliseconds. `u64::MAX` disables keep-alive comments.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<SseWriter<R, W>, ResponseError>` - The writer, or an error if the head
+    ///   could not be sent.
+    pub async fn start(
+        stream: ArcRwLockStream<R, W>,
+        response: &mut Response,
+        keep_alive_interval_ms: u64,
+    ) -> Result<Self, ResponseError> {
+        response
+            .set_header(CONTENT_TYPE, TEXT_EVENT_STREAM)
+            .set_header(CACHE_CONTROL, NO_CACHE)
+            .set_body(Vec::new());
+        stream.try_send(response.build()).await?;
+        stream.try_flush().await?;
+        Ok(Self {
+            stream,
+            keep_alive_interval_ms,
+            sent_count: 0,
+        })
+    }
+
+    /// Sends an event and flushes it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&SseEvent` - The event to send.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the event was sent, or an error.
+    pub async fn send_event(&mut self, event: &SseEvent) -> Result<(), ResponseError> {
+        self.stream.try_send(event.build()).await?;
+        self.stream.try_flush().await?;
+        self.sent_count += 1;
+        Ok(())
+    }
+
+    /// Sends a keep-alive comment and flushes it.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok if the comment was sent, or an error.
+    pub async fn send_keep_alive(&self) -> Result<(), ResponseError> {
+        self.stream.try_send(SSE_KEEP_ALIVE_COMMENT).await?;
+        self.stream.try_flush().await
+    }
+
+    /// Sends events from a channel until it is closed.
+    ///
+    /// A keep-alive comment is sent whenever no event arrives within the keep-alive
+    /// interval.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut Receiver<SseEvent>` - The channel the events are received from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ResponseError>` - Ok once the channel is closed, or an error if a
+    ///   write failed.
+    pub async fn forward(
+        &mut self,
+        receiver: &mut Receiver<SseEvent>,
+    ) -> Result<(), ResponseError> {
+        loop {
+            let event_opt: Option<SseEvent> = if self.keep_alive_interval_ms == u64::MAX {
+                receiver.recv().await
+            } else {
+                let duration: Duration = Duration::from_millis(self.keep_alive_interval_ms);
+                match timeout(duration, receiver.recv()).await {
+                    Ok(event_opt) => event_opt,
+                    Err(_) => {
+                        self.send_keep_alive().await?;
+                        continue;
+                    }
+                }
+            };
+            match event_opt {
+                Some(event) => self.send_event(&event).await?,
+                None => return Ok(()),
+            }
+        }
+    }
+}
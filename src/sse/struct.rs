@@ -0,0 +1,67 @@
+use crate::*;
+
+/// A Server-Sent Events event.
+///
+/// Data may span several lines; each line is sent as its own `data` field.
+#[derive(Clone, Data, Debug, Default, Deserialize, Eq, New, PartialEq, Serialize)]
+pub struct SseEvent {
+    /// The event id, remembered by the client as the last event id.
+    #[set]
+    pub(super) id: Option<String>,
+    /// The event type, `message` when not set.
+    #[set]
+    pub(super) event: Option<String>,
+    /// The event data.
+    #[set]
+    pub(super) data: String,
+    /// The reconnection time the client should use, in milliseconds.
+    #[get(type(copy))]
+    #[set]
+    pub(super) retry: Option<u64>,
+}
+
+/// Incremental parser for a Server-Sent Events stream.
+///
+/// The parser does not perform any I/O. Bytes are fed through `feed` as they
+/// arrive and complete events are returned as soon as their terminating empty
+/// line is received.
+#[derive(Clone, Debug, Default, Eq, Getter, PartialEq)]
+pub struct SseParser {
+    /// The partial line collected across calls to `feed`.
+    #[get(skip)]
+    pub(super) line: Vec<u8>,
+    /// Whether the previous byte was a carriage return ending a line.
+    #[get(skip)]
+    pub(super) after_carriage_return: bool,
+    /// The data lines of the event being assembled.
+    #[get(skip)]
+    pub(super) data: String,
+    /// Whether a data field was received for the event being assembled.
+    #[get(skip)]
+    pub(super) has_data: bool,
+    /// The type of the event being assembled.
+    #[get(skip)]
+    pub(super) event: Option<String>,
+    /// The reconnection time of the event being assembled.
+    #[get(skip)]
+    pub(super) retry: Option<u64>,
+    /// The last event id, which persists across events.
+    pub(super) last_event_id: Option<String>,
+}
+
+/// Writer that sends Server-Sent Events over a stream.
+///
+/// When the writer forwards events from a channel, a keep-alive comment is sent
+/// whenever no event was sent for the keep-alive interval.
+#[derive(Debug, Getter)]
+pub struct SseWriter<R, W> {
+    /// The stream events are written to.
+    #[get(skip)]
+    pub(super) stream: ArcRwLockStream<R, W>,
+    /// Time without events before a keep-alive comment is sent, in milliseconds.
+    #[get(type(copy))]
+    pub(super) keep_alive_interval_ms: u64,
+    /// The number of events sent so far.
+    #[get(type(copy))]
+    pub(super) sent_count: usize,
+}
@@ -0,0 +1,88 @@
+use crate::*;
+
+use tokio::{
+    io::{DuplexStream, duplex},
+    sync::mpsc::{Sender, channel},
+};
+
+#[test]
+fn sse_event_build_and_parse() {
+    let mut event: SseEvent = SseEvent::from_data("first\nsecond\r\nthird");
+    event
+        .set_id(Some("7".to_string()))
+        .set_event(Some("update".to_string()))
+        .set_retry(Some(3000));
+    let encoded: Vec<u8> = event.build();
+    assert_eq!(
+        encoded,
+        b"id: 7\nevent: update\nretry: 3000\ndata: first\ndata: second\ndata: third\n\n"
+    );
+    let mut parser: SseParser = SseParser::default();
+    let events: Vec<SseEvent> = parser.feed(&encoded);
+    event.set_data("first\nsecond\nthird".to_string());
+    assert_eq!(events, vec![event]);
+    assert_eq!(parser.try_get_last_event_id(), &Some("7".to_string()));
+    assert_eq!(
+        SseParser::default().feed(&SseEvent::from_data("").build()),
+        vec![SseEvent::from_data("")]
+    );
+}
+
+#[test]
+fn sse_parser_spec_rules() {
+    let mut parser: SseParser = SseParser::new(Some("1".to_string()));
+    let data: &[u8] =
+        b": comment\r\ndata:no space\rdata:  two spaces\r\n\r\nevent: ignored\n\nid: 2\nretry: x\nunknown: y\ndata\n\n";
+    let mut events: Vec<SseEvent> = Vec::new();
+    for byte in data {
+        events.extend(parser.feed(std::slice::from_ref(byte)));
+    }
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].get_data(), "no space\n two spaces");
+    assert_eq!(events[0].try_get_id(), &Some("1".to_string()));
+    assert_eq!(events[0].try_get_event(), &None);
+    assert_eq!(events[1].get_data(), "");
+    assert_eq!(events[1].try_get_id(), &Some("2".to_string()));
+    assert_eq!(events[1].get_retry(), None);
+}
+
+#[tokio::test]
+async fn sse_writer_forward_with_keep_alive() {
+    let (client, server): (DuplexStream, DuplexStream) = duplex(4096);
+    let client_stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    let server_stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(server);
+    let (sender, mut receiver): (Sender<SseEvent>, Receiver<SseEvent>) = channel(4);
+    let writer_task = tokio::spawn(async move {
+        let mut writer: SseWriter<_, _> =
+            SseWriter::start(server_stream, &mut Response::default(), 10)
+                .await
+                .unwrap();
+        writer.forward(&mut receiver).await.unwrap();
+        writer.get_sent_count()
+    });
+    sender.send(SseEvent::from_data("one")).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    sender.send(SseEvent::from_data("two")).await.unwrap();
+    drop(sender);
+    assert_eq!(writer_task.await.unwrap(), 2);
+    drop(client_stream.write().await.shutdown().await);
+    let response: Response =
+        Response::from_stream(&client_stream, &Method::Get, &RequestConfig::default())
+            .await
+            .unwrap();
+    assert_eq!(response.get_header_back(CONTENT_TYPE), TEXT_EVENT_STREAM);
+    assert!(response.get_body_string().contains(SSE_KEEP_ALIVE_COMMENT));
+    let data: Vec<String> = response
+        .get_sse_events()
+        .iter()
+        .map(|event: &SseEvent| event.get_data().clone())
+        .collect();
+    assert_eq!(data, vec!["one".to_string(), "two".to_string()]);
+}
+
+#[test]
+fn request_last_event_id() {
+    let request: Request = RequestBuilder::new().last_event_id("42").unwrap().build();
+    assert_eq!(request.try_get_last_event_id(), Some("42".to_string()));
+    assert_eq!(RequestBuilder::new().build().try_get_last_event_id(), None);
+}
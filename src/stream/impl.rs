@@ -1,7 +1,32 @@
 use crate::*;
 
+/// Blanket implementation of `AsyncReadWrite` for all thread-safe async streams.
+impl<T> AsyncReadWrite for T where T: AsyncRead + AsyncWrite + Unpin + Send + Sync {}
+
+/// Blanket implementation of `AsyncReadHalf` for all thread-safe async readers.
+impl<T> AsyncReadHalf for T where T: AsyncRead + Unpin + Send + Sync {}
+
+/// Blanket implementation of `AsyncWriteHalf` for all thread-safe async writers.
+impl<T> AsyncWriteHalf for T where T: AsyncWrite + Unpin + Send + Sync {}
+
+/// Implementation of `Clone` trait for `ArcRwLockStream`.
+impl<R, W> Clone for ArcRwLockStream<R, W> {
+    /// Clones the stream wrapper, sharing the same underlying halves.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A wrapper pointing to the same Arcs.
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            reader: self.reader.clone(),
+            writer: self.writer.clone(),
+        }
+    }
+}
+
 /// Implementation of `PartialEq` trait for `ArcRwLockStream`.
-impl PartialEq for ArcRwLockStream {
+impl<R, W> PartialEq for ArcRwLockStream<R, W> {
     /// Compares two `ArcRwLockStream` instances for equality.
     ///
     /// # Arguments
@@ -10,66 +35,139 @@ impl PartialEq for ArcRwLockStream {
     ///
     /// # Returns
     ///
-    /// - `bool` - `true` if the instances point to the same Arc, `false` otherwise.
+    /// - `bool` - `true` if the instances point to the same Arcs, `false` otherwise.
     fn eq(&self, other: &Self) -> bool {
-        Arc::as_ptr(self.get_0()) == Arc::as_ptr(other.get_0())
+        Arc::ptr_eq(&self.reader, &other.reader) && Arc::ptr_eq(&self.writer, &other.writer)
     }
 }
 
 /// Implementation of `Eq` trait for `ArcRwLockStream`.
-impl Eq for ArcRwLockStream {}
+impl<R, W> Eq for ArcRwLockStream<R, W> {}
 
 impl ArcRwLockStream {
+    /// Creates a new ArcRwLockStream from a TcpStream.
+    ///
+    /// Splits the stream into owned halves and wraps each in an Arc<RwLock<_>>.
+    ///
+    /// # Arguments
+    ///
+    /// - `TcpStream` - The raw stream to wrap.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcRwLockStream` - The new thread-safe stream wrapper.
+    #[inline(always)]
+    pub fn from_stream(stream: TcpStream) -> Self {
+        let (reader, writer): (OwnedReadHalf, OwnedWriteHalf) = stream.into_split();
+        Self::from_halves(reader, writer)
+    }
+
     /// Creates a new ArcRwLockStream from an Arc<RwLock<TcpStream>>.
     ///
+    /// Kept for existing callers. The stream is taken out of the lock and split like
+    /// `from_stream`, because the read and write halves are locked separately.
+    ///
     /// # Arguments
     ///
-    /// - `Arc<RwLock<TcpStream>>` - The stream to wrap.
+    /// - `ArcRwLock<TcpStream>` - The stream to wrap, which must not be shared.
     ///
     /// # Returns
     ///
-    /// - `ArcRwLockStream` - The new stream wrapper.
+    /// - `ArcRwLockStream` - The new thread-safe stream wrapper.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if other clones of the `Arc` are still alive, since a
+    /// shared stream cannot be split.
+    #[deprecated(note = "use `ArcRwLockStream::from_stream` with the `TcpStream` instead")]
     #[inline(always)]
     pub fn from(arc_rw_lock_stream: ArcRwLock<TcpStream>) -> Self {
-        Self(arc_rw_lock_stream)
+        let stream: TcpStream = Arc::try_unwrap(arc_rw_lock_stream)
+            .expect("ArcRwLockStream::from requires the only reference to the stream")
+            .into_inner();
+        Self::from_stream(stream)
     }
+}
 
-    /// Creates a new ArcRwLockStream from a TcpStream.
+impl<S> ArcRwLockIoStream<S>
+where
+    S: AsyncReadWrite,
+{
+    /// Creates a new ArcRwLockStream from any async stream.
     ///
-    /// Wraps the stream in an Arc<RwLock<_>>.
+    /// Splits the stream with `tokio::io::split` and wraps each half in an Arc<RwLock<_>>.
     ///
     /// # Arguments
     ///
-    /// - `TcpStream` - The raw stream to wrap.
+    /// - `S` - The raw stream to wrap.
     ///
     /// # Returns
     ///
-    /// - `ArcRwLockStream` - The new thread-safe stream wrapper.
+    /// - `ArcRwLockIoStream<S>` - The new thread-safe stream wrapper.
     #[inline(always)]
-    pub fn from_stream(stream: TcpStream) -> Self {
-        Self(arc_rwlock(stream))
+    pub fn from_io(stream: S) -> Self {
+        let (reader, writer): (ReadHalf<S>, WriteHalf<S>) = split(stream);
+        Self::from_halves(reader, writer)
+    }
+}
+
+impl<R, W> ArcRwLockStream<R, W>
+where
+    R: AsyncReadHalf,
+    W: AsyncWriteHalf,
+{
+    /// Creates a new ArcRwLockStream from separate read and write halves.
+    ///
+    /// # Arguments
+    ///
+    /// - `R` - The read half.
+    /// - `W` - The write half.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcRwLockStream<R, W>` - The new thread-safe stream wrapper.
+    #[inline(always)]
+    pub fn from_halves(reader: R, writer: W) -> Self {
+        Self {
+            reader: arc_rwlock(reader),
+            writer: arc_rwlock(writer),
+        }
+    }
+
+    /// Gets a read lock on the read half.
+    ///
+    /// Allows shared access to the read half, for example to query the peer address.
+    /// The guard covers only the read half, such as an `OwnedReadHalf` for a TCP stream,
+    /// not the whole stream. `OwnedReadHalf` provides `peer_addr` and `local_addr`
+    /// like `TcpStream`, and `read` no longer blocks sending on the write half.
+    ///
+    /// # Returns
+    ///
+    /// - `RwLockReadGuard<'_, R>` - The read guard for the read half.
+    pub async fn read(&'_ self) -> RwLockReadGuard<'_, R> {
+        self.reader.read().await
     }
 
-    /// Gets a read lock on the inner TcpStream.
+    /// Gets a write lock on the read half.
     ///
-    /// Allows shared read access to the stream.
+    /// Allows exclusive access for reading data without blocking the write half.
     ///
     /// # Returns
     ///
-    /// - `RwLockReadGuard<'_, TcpStream>` - The read guard for the stream.
-    pub async fn read(&'_ self) -> RwLockReadGuard<'_, TcpStream> {
-        self.get_0().read().await
+    /// - `RwLockWriteGuard<'_, R>` - The write guard for the read half.
+    pub(crate) async fn read_mut(&'_ self) -> RwLockWriteGuard<'_, R> {
+        self.reader.write().await
     }
 
-    /// Gets a write lock on the inner TcpStream.
+    /// Gets a write lock on the write half.
     ///
-    /// Allows exclusive write access to the stream.
+    /// Allows exclusive write access to the stream without blocking the read half.
     ///
     /// # Returns
     ///
-    /// - `RwLockWriteGuard<'_, TcpStream>` - The write guard for the stream.
-    pub(crate) async fn write(&'_ self) -> RwLockWriteGuard<'_, TcpStream> {
-        self.get_0().write().await
+    /// - `RwLockWriteGuard<'_, W>` - The write guard for the write half.
+    pub(crate) async fn write(&'_ self) -> RwLockWriteGuard<'_, W> {
+        self.writer.write().await
     }
 
     /// Sends HTTP response data over the stream.
@@ -150,7 +248,7 @@ impl ArcRwLockStream {
         I: IntoIterator<Item = D>,
         D: AsRef<[u8]>,
     {
-        let mut stream: RwLockWriteGuard<'_, TcpStream> = self.write().await;
+        let mut stream: RwLockWriteGuard<'_, W> = self.write().await;
         for data in data_iter {
             stream.write_all(data.as_ref()).await?;
         }
@@ -1,5 +1,8 @@
 mod r#impl;
 mod r#struct;
+#[cfg(test)]
+mod test;
+mod r#trait;
 mod r#type;
 
-pub use {r#struct::*, r#type::*};
+pub use {r#struct::*, r#trait::*, r#type::*};
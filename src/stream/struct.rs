@@ -1,11 +1,21 @@
 use crate::*;
 
-/// Thread-safe TCP stream wrapper.
+/// Thread-safe stream wrapper with separately locked read and write halves.
 ///
-/// Provides shared access to a TcpStream using Arc and RwLock.
+/// Reading from the stream never blocks writing to it, so a task waiting for the
+/// next frame from an idle client does not delay messages sent by other tasks.
+/// The halves default to the owned halves of a `TcpStream`, but any
+/// `AsyncReadWrite` stream such as a Unix socket, a TLS stream or an in-memory
+/// duplex stream can be wrapped.
 ///
 /// # Fields
 ///
-/// - `Arc<RwLock<TcpStream>>` - The protected TCP stream.
-#[derive(Clone, Debug, Getter)]
-pub struct ArcRwLockStream(#[get(pub(super))] pub(super) ArcRwLock<TcpStream>);
+/// - `Arc<RwLock<R>>` - The protected read half.
+/// - `Arc<RwLock<W>>` - The protected write half.
+#[derive(Debug)]
+pub struct ArcRwLockStream<R = OwnedReadHalf, W = OwnedWriteHalf> {
+    /// The protected read half.
+    pub(super) reader: ArcRwLock<R>,
+    /// The protected write half.
+    pub(super) writer: ArcRwLock<W>,
+}
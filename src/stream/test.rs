@@ -0,0 +1,22 @@
+use crate::*;
+
+use tokio::net::TcpListener;
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn arc_rwlock_stream_from_shared_tcp_stream() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: std::net::SocketAddr = listener.local_addr().unwrap();
+    let client: TcpStream = TcpStream::connect(address).await.unwrap();
+    let (server, _): (TcpStream, std::net::SocketAddr) = listener.accept().await.unwrap();
+    let stream: ArcRwLockStream = ArcRwLockStream::from(arc_rwlock(server));
+    assert_eq!(
+        stream.read().await.peer_addr().unwrap(),
+        client.local_addr().unwrap()
+    );
+    stream.send("ping").await;
+    let mut client: TcpStream = client;
+    let mut buffer: [u8; 4] = [0; 4];
+    client.read_exact(&mut buffer).await.unwrap();
+    assert_eq!(&buffer, b"ping");
+}
@@ -0,0 +1,19 @@
+use crate::*;
+
+/// Represents a transport stream that can be split into the halves of an `ArcRwLockStream`.
+///
+/// This trait combines the capabilities of `AsyncRead`, `AsyncWrite`, `Unpin`, `Send` and `Sync`,
+/// and is implemented for every type that provides them.
+pub trait AsyncReadWrite: AsyncRead + AsyncWrite + Unpin + Send + Sync {}
+
+/// Represents the read half of an `ArcRwLockStream`.
+///
+/// This trait combines the capabilities of `AsyncRead`, `Unpin`, `Send` and `Sync`,
+/// and is implemented for every type that provides them.
+pub trait AsyncReadHalf: AsyncRead + Unpin + Send + Sync {}
+
+/// Represents the write half of an `ArcRwLockStream`.
+///
+/// This trait combines the capabilities of `AsyncWrite`, `Unpin`, `Send` and `Sync`,
+/// and is implemented for every type that provides them.
+pub trait AsyncWriteHalf: AsyncWrite + Unpin + Send + Sync {}
@@ -3,6 +3,9 @@ use crate::*;
 /// A thread-safe reference-counted `TcpStream`.
 pub type ArcStream = Arc<TcpStream>;
 
+/// A thread-safe stream wrapper around the split halves of a generic async stream.
+pub type ArcRwLockIoStream<S> = ArcRwLockStream<ReadHalf<S>, WriteHalf<S>>;
+
 /// A socket host represented by an IP address.
 pub type SocketHost = IpAddr;
 
@@ -0,0 +1,2 @@
+/// The WebSocket protocol version defined by RFC 6455, sent in `Sec-WebSocket-Version`.
+pub const WEBSOCKET_VERSION_13: &str = "13";
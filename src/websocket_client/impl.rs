@@ -0,0 +1,264 @@
+use crate::*;
+
+impl WebSocketClientHandshake {
+    /// Creates the upgrade request for a WebSocket connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The host, and port if not the default, sent in the `Host` header.
+    /// - `AsRef<str>` - The request target, a path with an optional query string.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<WebSocketClientHandshake, RequestError>` - The handshake with a freshly
+    ///   generated key, `InvalidHeaderValue` if the host contains a CR, LF or NUL
+    ///   character, or `InvalidRequestTarget` if the target contains whitespace or a
+    ///   control character.
+    pub fn new<H, T>(host: H, target: T) -> Result<Self, RequestError>
+    where
+        H: AsRef<str>,
+        T: AsRef<str>,
+    {
+        Http::check_header(HOST, host.as_ref())?;
+        if target
+            .as_ref()
+            .bytes()
+            .any(|byte: u8| byte.is_ascii_whitespace() || byte.is_ascii_control())
+        {
+            return Err(RequestError::InvalidRequestTarget(HttpStatus::BadRequest));
+        }
+        let target: String = match target.as_ref() {
+            EMPTY_STR => DEFAULT_HTTP_PATH.to_string(),
+            target => target.to_string(),
+        };
+        let (path, query): (&str, &str) = target.split_once(QUERY).unwrap_or((&target, EMPTY_STR));
+        let key: String = WebSocketFrame::generate_key();
+        let mut request: Request = Request::default();
+        request.set_method(Method::Get);
+        request.set_version(HttpVersion::Http1_1);
+        request.set_host(host.as_ref().to_string());
+        request.set_path(path.to_string());
+        request.set_raw_query(query);
+        let mut handshake: Self = Self {
+            request,
+            target: target.clone(),
+            key: key.clone(),
+        };
+        handshake
+            .insert_header(HOST, host.as_ref().to_string())
+            .insert_header(UPGRADE, WEBSOCKET.to_string())
+            .insert_header(CONNECTION, UPGRADE.to_string())
+            .insert_header(SEC_WEBSOCKET_KEY, key)
+            .insert_header(SEC_WEBSOCKET_VERSION, WEBSOCKET_VERSION_13.to_string());
+        Ok(handshake)
+    }
+
+    /// Sets a header on the upgrade request without checking it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The lowercase header key.
+    /// - `String` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - A mutable reference to self for chaining.
+    fn insert_header(&mut self, key: &str, value: String) -> &mut Self {
+        let mut values: RequestHeadersValue = VecDeque::with_capacity(1);
+        values.push_back(value);
+        self.request
+            .get_mut_headers()
+            .insert(key.to_string(), values);
+        self
+    }
+
+    /// Sets a header on the upgrade request, replacing any existing values.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header key.
+    /// - `AsRef<str>` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, RequestError>` - A mutable reference to self for chaining,
+    ///   `InvalidHeaderName` if the key is not a token, or `InvalidHeaderValue` if the
+    ///   value contains a CR, LF or NUL character.
+    pub fn set_header<K, V>(&mut self, key: K, value: V) -> Result<&mut Self, RequestError>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        Http::check_header(key.as_ref(), value.as_ref())?;
+        Ok(self.insert_header(
+            &key.as_ref().to_ascii_lowercase(),
+            value.as_ref().to_string(),
+        ))
+    }
+
+    /// Offers the `permessage-deflate` extension without context takeover.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Self` - A mutable reference to self for chaining.
+    #[inline(always)]
+    pub fn offer_deflate(&mut self) -> &mut Self {
+        self.insert_header(
+            SEC_WEBSOCKET_EXTENSIONS,
+            WebSocketDeflateConfig::no_context_takeover().to_header_value(),
+        )
+    }
+
+    /// Offers subprotocols in order of preference.
+    ///
+    /// # Arguments
+    ///
+    /// - `IntoIterator<Item = AsRef<str>>` - The subprotocols to offer.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Self, RequestError>` - A mutable reference to self for chaining, or
+    ///   `InvalidHeaderValue` if a subprotocol contains a CR, LF or NUL character.
+    pub fn offer_protocols<I, P>(&mut self, protocols: I) -> Result<&mut Self, RequestError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let protocols: Vec<String> = protocols
+            .into_iter()
+            .map(|protocol: P| protocol.as_ref().to_string())
+            .collect();
+        self.set_header(SEC_WEBSOCKET_PROTOCOL, protocols.join(COMMA))
+    }
+
+    /// Serializes the upgrade request.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The request line and headers in HTTP/1.1 wire format.
+    pub fn build(&self) -> Vec<u8> {
+        let mut request_string: String = String::with_capacity(DEFAULT_BUFFER_SIZE);
+        request_string.push_str(&self.request.get_method().to_string());
+        request_string.push_str(SPACE);
+        request_string.push_str(&self.target);
+        request_string.push_str(SPACE);
+        request_string.push_str(&self.request.get_version().to_string());
+        request_string.push_str(HTTP_BR);
+        for (key, values) in self.request.get_headers().iter() {
+            for value in values.iter() {
+                request_string.push_str(key);
+                request_string.push_str(COLON_SPACE);
+                request_string.push_str(value);
+                request_string.push_str(HTTP_BR);
+            }
+        }
+        request_string.push_str(HTTP_BR);
+        request_string.into_bytes()
+    }
+
+    /// Checks whether a response header contains a value, ignoring ASCII case.
+    ///
+    /// Comma-separated values are matched one by one.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Response` - The handshake response.
+    /// - `&str` - The header key.
+    /// - `&str` - The expected value.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether any value of the header matches.
+    fn has_header_token(response: &Response, key: &str, token: &str) -> bool {
+        response.try_get_header(key).is_some_and(|values| {
+            values
+                .iter()
+                .flat_map(|value: &String| value.split(COMMA))
+                .any(|item: &str| item.trim().eq_ignore_ascii_case(token))
+        })
+    }
+
+    /// Validates the server's response to the upgrade request.
+    ///
+    /// The response must have status `101`, `Upgrade: websocket`, a `Connection` header
+    /// containing `upgrade`, and a `Sec-WebSocket-Accept` matching the key. A selected
+    /// subprotocol must be one of the offered ones. An extension
+    /// is only accepted if `permessage-deflate` was offered and the server agreed to
+    /// parameters the codec supports.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Response` - The handshake response.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<WebSocketDeflateConfig>, RequestError>` - The negotiated
+    ///   `permessage-deflate` configuration, if any, or an error if the handshake failed.
+    pub fn validate_response(
+        &self,
+        response: &Response,
+    ) -> Result<Option<WebSocketDeflateConfig>, RequestError> {
+        let failed: RequestError = RequestError::WebSocketHandshakeFailed(HttpStatus::BadGateway);
+        let expected_accept: String = WebSocketFrame::generate_accept_key(&self.key);
+        let is_accepted: bool = response.get_status_code() == HttpStatus::SwitchingProtocols.code()
+            && Self::has_header_token(response, UPGRADE, WEBSOCKET)
+            && Self::has_header_token(response, CONNECTION, UPGRADE)
+            && response
+                .try_get_header_back(SEC_WEBSOCKET_ACCEPT)
+                .as_deref()
+                == Some(expected_accept.as_str());
+        let is_offered_protocol: bool = response
+            .try_get_header_back(SEC_WEBSOCKET_PROTOCOL)
+            .is_none_or(|protocol: String| self.request.get_ws_protocols().contains(&protocol));
+        if !is_accepted || !is_offered_protocol {
+            return Err(failed);
+        }
+        let extensions: String = match response.try_get_header(SEC_WEBSOCKET_EXTENSIONS) {
+            Some(values) => values.into_iter().collect::<Vec<String>>().join(COMMA),
+            None => return Ok(None),
+        };
+        if !self.request.has_header(SEC_WEBSOCKET_EXTENSIONS) {
+            return Err(failed);
+        }
+        WebSocketDeflateConfig::from_response(extensions)
+            .map(Some)
+            .ok_or(failed)
+    }
+
+    /// Performs the opening handshake on a connected stream.
+    ///
+    /// Sends the upgrade request, reads and validates the response, and returns a
+    /// client-side `WebSocketReader` that keeps any frames received with the response.
+    /// If `permessage-deflate` was negotiated it is enabled on the reader.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The connected stream.
+    /// - `RequestConfig` - Configuration for header limits, buffer size and WebSocket limits.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Response, WebSocketReader<R, W>), RequestError>` - The handshake response and
+    ///   the reader, or an error if the handshake failed.
+    pub async fn connect<R, W>(
+        &self,
+        stream: ArcRwLockStream<R, W>,
+        config: RequestConfig,
+    ) -> Result<(Response, WebSocketReader<R, W>), RequestError>
+    where
+        R: AsyncReadHalf,
+        W: AsyncWriteHalf,
+    {
+        stream.try_send(self.build()).await?;
+        stream.try_flush().await?;
+        let mut buffer: Vec<u8> = Vec::with_capacity(config.get_buffer_size());
+        let response: Response =
+            Response::read_from_stream(&stream, &mut buffer, &Method::Get, &config).await?;
+        let deflate: Option<WebSocketDeflateConfig> = self.validate_response(&response)?;
+        let mut reader: WebSocketReader<R, W> = WebSocketReader::client(stream, config, buffer);
+        if let Some(deflate) = deflate {
+            reader.set_deflate(deflate);
+        }
+        Ok((response, reader))
+    }
+}
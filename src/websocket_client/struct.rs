@@ -0,0 +1,15 @@
+use crate::*;
+
+/// Client side of the WebSocket opening handshake.
+///
+/// Builds the upgrade request with a fresh `Sec-WebSocket-Key` and checks that
+/// the server's `101` response answers that key.
+#[derive(Clone, Debug, Getter)]
+pub struct WebSocketClientHandshake {
+    /// The upgrade request sent to the server.
+    pub(super) request: Request,
+    /// The request target, path and query, as written in the request line.
+    pub(super) target: String,
+    /// The `Sec-WebSocket-Key` sent with the request.
+    pub(super) key: String,
+}
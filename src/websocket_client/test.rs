@@ -0,0 +1,161 @@
+use crate::*;
+
+use tokio::io::{DuplexStream, duplex};
+
+fn switching_response(handshake: &WebSocketClientHandshake) -> Response {
+    let mut response: Response = Response::default();
+    response.set_status_code(HttpStatus::SwitchingProtocols.code());
+    response
+        .set_header(UPGRADE, "WebSocket")
+        .set_header(CONNECTION, "Upgrade")
+        .set_header(
+            SEC_WEBSOCKET_ACCEPT,
+            WebSocketFrame::generate_accept_key(handshake.get_key()),
+        );
+    response
+}
+
+#[test]
+fn client_handshake_request() {
+    let handshake: WebSocketClientHandshake =
+        WebSocketClientHandshake::new("example.com:8080", "/chat?room=1").unwrap();
+    assert_eq!(handshake.get_key().len(), 24);
+    assert_ne!(
+        handshake.get_key(),
+        WebSocketClientHandshake::new("example.com", "")
+            .unwrap()
+            .get_key()
+    );
+    assert_eq!(handshake.get_request().get_path(), "/chat");
+    assert_eq!(handshake.get_request().get_query("room"), "1");
+    let request: String = String::from_utf8(handshake.build()).unwrap();
+    assert!(request.starts_with("GET /chat?room=1 HTTP/1.1\r\n"));
+    assert!(request.contains("host: example.com:8080\r\n"));
+    assert!(request.contains("upgrade: websocket\r\n"));
+    assert!(request.contains("sec-websocket-version: 13\r\n"));
+    assert!(request.contains(&format!("sec-websocket-key: {}\r\n", handshake.get_key())));
+    assert!(request.ends_with("\r\n\r\n"));
+}
+
+#[test]
+fn client_handshake_validate_response() {
+    let mut handshake: WebSocketClientHandshake =
+        WebSocketClientHandshake::new("localhost", "/").unwrap();
+    let mut response: Response = switching_response(&handshake);
+    assert_eq!(handshake.validate_response(&response), Ok(None));
+    response.set_header(
+        SEC_WEBSOCKET_EXTENSIONS,
+        "permessage-deflate; server_no_context_takeover",
+    );
+    assert!(handshake.validate_response(&response).is_err());
+    handshake.offer_deflate();
+    assert!(
+        handshake
+            .validate_response(&response)
+            .unwrap()
+            .is_some_and(|config| config.get_server_no_context_takeover())
+    );
+    response.set_header(SEC_WEBSOCKET_ACCEPT, "invalid");
+    assert_eq!(
+        handshake.validate_response(&response),
+        Err(RequestError::WebSocketHandshakeFailed(
+            HttpStatus::BadGateway
+        ))
+    );
+    let mut rejected: Response = switching_response(&handshake);
+    rejected.set_status_code(HttpStatus::Forbidden.code());
+    assert!(handshake.validate_response(&rejected).is_err());
+}
+
+#[tokio::test]
+async fn client_handshake_connect_and_exchange_messages() {
+    let (client, server): (DuplexStream, DuplexStream) = duplex(4096);
+    let handshake: WebSocketClientHandshake =
+        WebSocketClientHandshake::new("localhost", "/ws").unwrap();
+    let server_task: tokio::task::JoinHandle<Vec<u8>> = tokio::spawn(async move {
+        let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(server);
+        let mut connection: ConnectionReader<ReadHalf<DuplexStream>, WriteHalf<DuplexStream>> =
+            ConnectionReader::new(stream.clone(), RequestConfig::default());
+        let request: Request = connection.next_request().await.unwrap().unwrap();
+        assert!(request.is_ws_upgrade_type());
+        let accept: String =
+            WebSocketFrame::generate_accept_key(request.get_header_back(SEC_WEBSOCKET_KEY));
+        let mut response: Vec<u8> = format!(
+            "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {accept}\r\n\r\n"
+        )
+        .into_bytes();
+        response.extend(WebSocketFrame::encode_ws_frame(
+            true,
+            WebSocketOpcode::Text,
+            None,
+            "welcome",
+        ));
+        stream.send(response).await;
+        let mut buffer: Vec<u8> = connection.take_buffer();
+        loop {
+            if let Some((frame, _)) =
+                WebSocketFrame::try_decode_ws_frame(&buffer, DEFAULT_MAX_BODY_SIZE).unwrap()
+            {
+                return frame.get_payload_data().clone();
+            }
+            stream.read_mut().await.read_buf(&mut buffer).await.unwrap();
+        }
+    });
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(client);
+    let (response, mut reader): (
+        Response,
+        WebSocketReader<ReadHalf<DuplexStream>, WriteHalf<DuplexStream>>,
+    ) = handshake
+        .connect(stream, RequestConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(response.get_status_code(), 101);
+    assert_eq!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Text("welcome".to_string()))
+    );
+    reader
+        .send_message(&WebSocketMessage::Text("hi".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(server_task.await.unwrap(), b"hi".to_vec());
+}
+
+#[test]
+fn client_handshake_rejects_unoffered_protocol() {
+    let mut handshake: WebSocketClientHandshake =
+        WebSocketClientHandshake::new("localhost", "/").unwrap();
+    let mut response: Response = switching_response(&handshake);
+    response.set_header(SEC_WEBSOCKET_PROTOCOL, "mqtt");
+    assert!(handshake.validate_response(&response).is_err());
+    handshake.offer_protocols(["graphql-ws", "mqtt"]).unwrap();
+    assert_eq!(handshake.validate_response(&response), Ok(None));
+}
+
+#[test]
+fn client_handshake_rejects_header_injection() {
+    let mut handshake: WebSocketClientHandshake =
+        WebSocketClientHandshake::new("localhost", "/").unwrap();
+    assert_eq!(
+        handshake.set_header("X-Token", "a\r\nX-Injected: 1").err(),
+        Some(RequestError::InvalidHeaderValue(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        handshake.set_header("X Token", "a").err(),
+        Some(RequestError::InvalidHeaderName(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        handshake.offer_protocols(["chat\r\nX-Injected: 1"]).err(),
+        Some(RequestError::InvalidHeaderValue(HttpStatus::BadRequest))
+    );
+    assert!(!handshake.get_request().has_header("x-token"));
+    assert!(!handshake.get_request().has_header(SEC_WEBSOCKET_PROTOCOL));
+    assert_eq!(
+        WebSocketClientHandshake::new("localhost\r\nX-Injected: 1", "/").err(),
+        Some(RequestError::InvalidHeaderValue(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        WebSocketClientHandshake::new("localhost", "/ HTTP/1.1\r\nX-Injected: 1").err(),
+        Some(RequestError::InvalidRequestTarget(HttpStatus::BadRequest))
+    );
+}
@@ -0,0 +1,17 @@
+/// The name of the `permessage-deflate` WebSocket extension.
+pub const PERMESSAGE_DEFLATE: &str = "permessage-deflate";
+
+/// The extension parameter disabling context takeover for the server.
+pub const SERVER_NO_CONTEXT_TAKEOVER: &str = "server_no_context_takeover";
+
+/// The extension parameter disabling context takeover for the client.
+pub const CLIENT_NO_CONTEXT_TAKEOVER: &str = "client_no_context_takeover";
+
+/// The extension parameter limiting the LZ77 window of the server.
+pub const SERVER_MAX_WINDOW_BITS: &str = "server_max_window_bits";
+
+/// The extension parameter limiting the LZ77 window of the client.
+pub const CLIENT_MAX_WINDOW_BITS: &str = "client_max_window_bits";
+
+/// The bytes removed from the end of a compressed message and restored before decompression.
+pub const DEFLATE_MESSAGE_TAIL: [u8; 4] = [0x00, 0x00, 0xFF, 0xFF];
@@ -0,0 +1,263 @@
+use crate::*;
+
+impl WebSocketDeflateConfig {
+    /// Creates a configuration without context takeover in either direction.
+    ///
+    /// Every message is compressed and decompressed independently, which is what the
+    /// codec of this crate implements. This is used as the server response and as
+    /// the client offer.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketDeflateConfig` - The configuration without context takeover.
+    #[inline(always)]
+    pub fn no_context_takeover() -> Self {
+        Self {
+            server_no_context_takeover: true,
+            client_no_context_takeover: true,
+            ..Default::default()
+        }
+    }
+
+    /// Parses a window bits parameter value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The parameter value, optionally quoted.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u8>` - The window bits if the value is an integer from 8 to 15.
+    fn parse_window_bits(value: &str) -> Option<u8> {
+        let value: &str = value.trim_matches('"');
+        if value.is_empty() || value.starts_with('0') || !value.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        value
+            .parse::<u8>()
+            .ok()
+            .filter(|bits: &u8| (8..=15).contains(bits))
+    }
+
+    /// Parses the parameters of a single `permessage-deflate` extension entry.
+    ///
+    /// # Arguments
+    ///
+    /// - `Iterator<Item = &str>` - The parameters following the extension name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<WebSocketDeflateConfig>` - The parameters, or `None` if a parameter is
+    ///   unknown, duplicated or has an invalid value.
+    fn parse_params<'a, I>(params: I) -> Option<Self>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let mut config: Self = Self::default();
+        let mut seen: [bool; 4] = [false; 4];
+        for param in params {
+            let (name, value): (&str, Option<&str>) = match param.split_once(EQUAL) {
+                Some((name, value)) => (name.trim(), Some(value.trim())),
+                None => (param.trim(), None),
+            };
+            let index: usize = match name.to_ascii_lowercase().as_str() {
+                SERVER_NO_CONTEXT_TAKEOVER if value.is_none() => {
+                    config.server_no_context_takeover = true;
+                    0
+                }
+                CLIENT_NO_CONTEXT_TAKEOVER if value.is_none() => {
+                    config.client_no_context_takeover = true;
+                    1
+                }
+                SERVER_MAX_WINDOW_BITS => {
+                    config.server_max_window_bits = Some(Self::parse_window_bits(value?)?);
+                    2
+                }
+                CLIENT_MAX_WINDOW_BITS => {
+                    config.client_max_window_bits = match value {
+                        Some(value) => Some(Self::parse_window_bits(value)?),
+                        None => Some(15),
+                    };
+                    3
+                }
+                _ => return None,
+            };
+            if seen[index] {
+                return None;
+            }
+            seen[index] = true;
+        }
+        Some(config)
+    }
+
+    /// Parses all valid `permessage-deflate` entries of a `Sec-WebSocket-Extensions` header.
+    ///
+    /// Other extensions and entries with invalid parameters are skipped. A
+    /// `client_max_window_bits` parameter without a value is reported as 15.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<WebSocketDeflateConfig>` - The offers in order of preference.
+    pub fn parse_offers<H>(header: H) -> Vec<Self>
+    where
+        H: AsRef<str>,
+    {
+        header
+            .as_ref()
+            .split(COMMA)
+            .filter_map(|extension: &str| {
+                let mut parts: std::str::Split<'_, &str> = extension.split(SEMICOLON);
+                let name: &str = parts.next().unwrap_or_default().trim();
+                if !name.eq_ignore_ascii_case(PERMESSAGE_DEFLATE) {
+                    return None;
+                }
+                Self::parse_params(parts)
+            })
+            .collect()
+    }
+
+    /// Selects the first acceptable `permessage-deflate` offer from a client.
+    ///
+    /// Offers that limit the server window below 15 bits are declined because the
+    /// compressor always uses a 32 KiB window. The accepted configuration disables
+    /// context takeover in both directions, which a server may always request.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The `Sec-WebSocket-Extensions` request header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<WebSocketDeflateConfig>` - The configuration to respond with, or `None`
+    ///   if no offer can be accepted.
+    pub fn negotiate<H>(header: H) -> Option<Self>
+    where
+        H: AsRef<str>,
+    {
+        Self::parse_offers(header)
+            .into_iter()
+            .any(|offer: Self| {
+                offer
+                    .server_max_window_bits
+                    .is_none_or(|bits: u8| bits == 15)
+            })
+            .then(Self::no_context_takeover)
+    }
+
+    /// Validates the server response to an offer made with `no_context_takeover`.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The `Sec-WebSocket-Extensions` response header value.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<WebSocketDeflateConfig>` - The accepted configuration, or `None` if the
+    ///   server did not accept the extension with parameters the codec supports.
+    pub fn from_response<H>(header: H) -> Option<Self>
+    where
+        H: AsRef<str>,
+    {
+        let offers: Vec<Self> = Self::parse_offers(header);
+        let [config]: [Self; 1] = offers.try_into().ok()?;
+        let is_supported: bool = config.server_no_context_takeover
+            && config
+                .client_max_window_bits
+                .is_none_or(|bits: u8| bits == 15);
+        is_supported.then_some(config)
+    }
+
+    /// Renders the configuration as a `Sec-WebSocket-Extensions` header value.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The header value.
+    pub fn to_header_value(&self) -> String {
+        let mut value: String = PERMESSAGE_DEFLATE.to_string();
+        if self.server_no_context_takeover {
+            value.push_str(SEMICOLON_SPACE);
+            value.push_str(SERVER_NO_CONTEXT_TAKEOVER);
+        }
+        if self.client_no_context_takeover {
+            value.push_str(SEMICOLON_SPACE);
+            value.push_str(CLIENT_NO_CONTEXT_TAKEOVER);
+        }
+        if let Some(bits) = self.server_max_window_bits {
+            value.push_str(&format!("{SEMICOLON_SPACE}{SERVER_MAX_WINDOW_BITS}={bits}"));
+        }
+        if let Some(bits) = self.client_max_window_bits {
+            value.push_str(&format!("{SEMICOLON_SPACE}{CLIENT_MAX_WINDOW_BITS}={bits}"));
+        }
+        value
+    }
+
+    /// Compresses a complete message payload.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The uncompressed payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The compressed payload without the trailing empty stored block.
+    pub fn compress(payload: &[u8]) -> Vec<u8> {
+        let mut compressed: Vec<u8> = Compress::Deflate
+            .encode(payload, DEFAULT_BUFFER_SIZE)
+            .into_owned();
+        if compressed.ends_with(&DEFLATE_MESSAGE_TAIL) {
+            compressed.truncate(compressed.len() - DEFLATE_MESSAGE_TAIL.len());
+        }
+        compressed
+    }
+
+    /// Decompresses a complete message payload.
+    ///
+    /// Decompression stops as soon as the output exceeds the maximum size, so a small
+    /// compressed message cannot expand into an unbounded allocation.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The compressed payload as received.
+    /// - `usize` - The maximum size of the decompressed payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The decompressed payload, or an error if the data
+    ///   is corrupted or too large.
+    pub fn decompress(payload: &[u8], max_size: usize) -> Result<Vec<u8>, RequestError> {
+        let input: Vec<u8> = [payload, &DEFLATE_MESSAGE_TAIL].concat();
+        let mut decompress: Decompress = Decompress::new(false);
+        let mut output: Vec<u8> = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+        loop {
+            if output.len() == output.capacity() {
+                output.reserve(DEFAULT_BUFFER_SIZE);
+            }
+            let consumed: usize = decompress.total_in() as usize;
+            let produced: u64 = decompress.total_out();
+            let status: Status = decompress
+                .decompress_vec(&input[consumed..], &mut output, FlushDecompress::Sync)
+                .map_err(|_| RequestError::WebSocketPayloadCorrupted(HttpStatus::BadRequest))?;
+            if output.len() > max_size && max_size != DEFAULT_LOW_SECURITY_MAX_BODY_SIZE {
+                return Err(RequestError::WebSocketFrameTooLarge(
+                    HttpStatus::PayloadTooLarge,
+                ));
+            }
+            let is_input_consumed: bool = decompress.total_in() as usize == input.len();
+            if status == Status::StreamEnd
+                || (is_input_consumed && output.len() < output.capacity())
+            {
+                return Ok(output);
+            }
+            if decompress.total_in() as usize == consumed && decompress.total_out() == produced {
+                return Err(RequestError::WebSocketPayloadCorrupted(
+                    HttpStatus::BadRequest,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod r#const;
+mod r#impl;
+mod r#struct;
+#[cfg(test)]
+mod test;
+
+pub use {r#const::*, r#struct::*};
@@ -0,0 +1,25 @@
+use crate::*;
+
+/// Parameters of the `permessage-deflate` WebSocket extension (RFC 7692).
+///
+/// Used both for parsing offers and responses from the `Sec-WebSocket-Extensions`
+/// header and for rendering the header value of an accepted configuration.
+#[derive(Clone, Copy, Data, Debug, Default, Deserialize, Eq, New, PartialEq, Serialize)]
+pub struct WebSocketDeflateConfig {
+    /// Whether the server resets its compression context after each message.
+    #[get(type(copy))]
+    #[set]
+    pub(super) server_no_context_takeover: bool,
+    /// Whether the client resets its compression context after each message.
+    #[get(type(copy))]
+    #[set]
+    pub(super) client_no_context_takeover: bool,
+    /// The base-2 logarithm of the LZ77 window the server may use, if limited.
+    #[get(type(copy))]
+    #[set]
+    pub(super) server_max_window_bits: Option<u8>,
+    /// The base-2 logarithm of the LZ77 window the client may use, if limited.
+    #[get(type(copy))]
+    #[set]
+    pub(super) client_max_window_bits: Option<u8>,
+}
@@ -0,0 +1,77 @@
+use crate::*;
+
+#[test]
+fn parse_deflate_offers() {
+    let offers: Vec<WebSocketDeflateConfig> = WebSocketDeflateConfig::parse_offers(
+        "x-webkit-deflate-frame, permessage-deflate; client_max_window_bits, \
+         permessage-deflate; server_max_window_bits=\"10\"; server_no_context_takeover, \
+         permessage-deflate; server_max_window_bits, permessage-deflate; unknown, \
+         permessage-deflate; client_no_context_takeover; client_no_context_takeover",
+    );
+    assert_eq!(offers.len(), 2);
+    assert_eq!(offers[0].get_client_max_window_bits(), Some(15));
+    assert_eq!(offers[1].get_server_max_window_bits(), Some(10));
+    assert!(offers[1].get_server_no_context_takeover());
+    assert_eq!(
+        WebSocketDeflateConfig::parse_offers("permessage-deflate; client_max_window_bits=16"),
+        vec![]
+    );
+}
+
+#[test]
+fn negotiate_deflate() {
+    let config: WebSocketDeflateConfig = WebSocketDeflateConfig::negotiate(
+        "permessage-deflate; server_max_window_bits=10, permessage-deflate",
+    )
+    .unwrap();
+    assert_eq!(config, WebSocketDeflateConfig::no_context_takeover());
+    assert_eq!(
+        config.to_header_value(),
+        "permessage-deflate; server_no_context_takeover; client_no_context_takeover"
+    );
+    assert_eq!(
+        WebSocketDeflateConfig::negotiate("permessage-deflate; server_max_window_bits=9"),
+        None
+    );
+    assert_eq!(
+        WebSocketDeflateConfig::from_response(config.to_header_value()),
+        Some(config)
+    );
+    assert_eq!(
+        WebSocketDeflateConfig::from_response("permessage-deflate"),
+        None
+    );
+}
+
+#[test]
+fn deflate_round_trip() {
+    let payload: Vec<u8> = b"compressible payload ".repeat(64);
+    let compressed: Vec<u8> = WebSocketDeflateConfig::compress(&payload);
+    assert!(compressed.len() < payload.len());
+    assert!(!compressed.ends_with(&DEFLATE_MESSAGE_TAIL));
+    assert_eq!(
+        WebSocketDeflateConfig::decompress(&compressed, payload.len()).unwrap(),
+        payload
+    );
+    assert_eq!(
+        WebSocketDeflateConfig::decompress(&compressed, payload.len() - 1),
+        Err(RequestError::WebSocketFrameTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+    assert_eq!(
+        WebSocketDeflateConfig::decompress(&[0xFF, 0xFF, 0xFF], 1024),
+        Err(RequestError::WebSocketPayloadCorrupted(
+            HttpStatus::BadRequest
+        ))
+    );
+}
+
+#[test]
+fn deflate_sync_flushed_message() {
+    let compressed: [u8; 7] = [0xF2, 0x48, 0xCD, 0xC9, 0xC9, 0x07, 0x00];
+    assert_eq!(
+        WebSocketDeflateConfig::decompress(&compressed, 1024).unwrap(),
+        b"Hello".to_vec()
+    );
+}
@@ -14,6 +14,7 @@ impl Default for WebSocketFrame {
     fn default() -> Self {
         Self {
             fin: false,
+            rsv1: false,
             opcode: WebSocketOpcode::Text,
             mask: false,
             payload_data: Vec::new(),
@@ -154,6 +155,72 @@ impl WebSocketOpcode {
 }
 
 impl WebSocketFrame {
+    /// Parses the header of a WebSocket frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw frame data.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(u8, u8, u64, usize)>` - The first and second header bytes, the payload length
+    ///   and the header length including the mask key, or `None` if the header is incomplete.
+    fn parse_frame_header(data: &[u8]) -> Option<(u8, u8, u64, usize)> {
+        if data.len() < 2 {
+            return None;
+        }
+        let mut index: usize = 2;
+        let mut payload_len: u64 = (data[1] & 0b0111_1111) as u64;
+        if payload_len == 126 {
+            payload_len = u16::from_be_bytes(data.get(index..index + 2)?.try_into().ok()?) as u64;
+            index += 2;
+        } else if payload_len == 127 {
+            payload_len = u64::from_be_bytes(data.get(index..index + 8)?.try_into().ok()?);
+            index += 8;
+        }
+        if (data[1] & 0b1000_0000) != 0 {
+            index += 4;
+        }
+        Some((data[0], data[1], payload_len, index))
+    }
+
+    /// Builds a frame from a complete header and payload, unmasking the payload if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw frame data, at least `header_len + payload_len` bytes long.
+    /// - `u8` - The first header byte.
+    /// - `u8` - The second header byte.
+    /// - `usize` - The header length including the mask key.
+    /// - `usize` - The payload length.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketFrame` - The decoded frame.
+    fn build_frame(
+        data: &[u8],
+        first_byte: u8,
+        second_byte: u8,
+        header_len: usize,
+        payload_len: usize,
+    ) -> WebSocketFrame {
+        let mask: bool = (second_byte & 0b1000_0000) != 0;
+        let mut payload: Vec<u8> = data[header_len..header_len + payload_len].to_vec();
+        if mask {
+            let mask_key: &[u8] = &data[header_len - 4..header_len];
+            for (i, byte) in payload.iter_mut().enumerate() {
+                *byte ^= mask_key[i % 4];
+            }
+        }
+        WebSocketFrame {
+            fin: (first_byte & 0b1000_0000) != 0,
+            rsv1: (first_byte & 0b0100_0000) != 0,
+            opcode: WebSocketOpcode::from_u8(first_byte & 0b0000_1111),
+            mask,
+            payload_data: payload,
+        }
+    }
+
     /// Decodes a WebSocket frame from the provided data slice.
     ///
     /// This function parses the raw bytes from a WebSocket stream according to the WebSocket protocol
@@ -174,56 +241,396 @@ impl WebSocketFrame {
         D: AsRef<[u8]>,
     {
         let data_ref: &[u8] = data.as_ref();
-        if data_ref.len() < 2 {
+        let (first_byte, second_byte, payload_len, header_len): (u8, u8, u64, usize) =
+            Self::parse_frame_header(data_ref)?;
+        let payload_len: usize = usize::try_from(payload_len).ok()?;
+        let frame_len: usize = header_len.checked_add(payload_len)?;
+        if data_ref.len() < frame_len {
             return None;
         }
-        let mut index: usize = 0;
-        let fin: bool = (data_ref[index] & 0b1000_0000) != 0;
-        let opcode: WebSocketOpcode = WebSocketOpcode::from_u8(data_ref[index] & 0b0000_1111);
-        index += 1;
-        let mask: bool = (data_ref[index] & 0b1000_0000) != 0;
-        let mut payload_len: usize = (data_ref[index] & 0b0111_1111) as usize;
-        index += 1;
-        if payload_len == 126 {
-            if data_ref.len() < index + 2 {
-                return None;
+        let frame: WebSocketFrame =
+            Self::build_frame(data_ref, first_byte, second_byte, header_len, payload_len);
+        Some((frame, frame_len))
+    }
+
+    /// Checks whether a close status code may be received according to RFC 6455.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the code is a valid close code.
+    #[inline(always)]
+    pub fn is_valid_close_code(code: u16) -> bool {
+        matches!(code, 1000..=1003 | 1007..=1014 | 3000..=4999)
+    }
+
+    /// Validates the payload of a close frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The unmasked close frame payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the status code and reason are valid.
+    fn validate_close_payload(payload: &[u8]) -> Result<(), RequestError> {
+        if payload.is_empty() {
+            return Ok(());
+        }
+        if payload.len() == 1 {
+            return Err(RequestError::WebSocketInvalidCloseCode(
+                HttpStatus::BadRequest,
+            ));
+        }
+        let code: u16 = u16::from_be_bytes([payload[0], payload[1]]);
+        if !Self::is_valid_close_code(code) {
+            return Err(RequestError::WebSocketInvalidCloseCode(
+                HttpStatus::BadRequest,
+            ));
+        }
+        std::str::from_utf8(&payload[2..])
+            .map_err(|_| RequestError::WebSocketInvalidUtf8(HttpStatus::BadRequest))?;
+        Ok(())
+    }
+
+    /// Decodes and validates a client WebSocket frame according to RFC 6455.
+    ///
+    /// Unlike `decode_ws_frame`, the header is validated before the payload is complete, so
+    /// oversized frames are rejected without buffering them. Frames must be masked, must not
+    /// set RSV bits, must not use reserved opcodes, and control frames must be final and carry
+    /// at most 125 bytes. Close frames must carry a valid status code and UTF-8 reason.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The raw data to decode into a WebSocket frame.
+    /// - `usize` - The maximum payload size of a single frame.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<(WebSocketFrame, usize)>, RequestError>` - The decoded frame and the
+    ///   number of bytes consumed, `None` if more data is needed, or an error if the frame
+    ///   violates the protocol.
+    #[inline(always)]
+    pub fn try_decode_ws_frame<D>(
+        data: D,
+        max_frame_size: usize,
+    ) -> Result<Option<(WebSocketFrame, usize)>, RequestError>
+    where
+        D: AsRef<[u8]>,
+    {
+        Self::try_decode_ws_frame_with_deflate(data, max_frame_size, false)
+    }
+
+    /// Decodes and validates a client WebSocket frame, optionally allowing compressed messages.
+    ///
+    /// Behaves like `try_decode_ws_frame`, except that when `permessage-deflate` was negotiated
+    /// the RSV1 bit is accepted on the first frame of a Text or Binary message.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The raw data to decode into a WebSocket frame.
+    /// - `usize` - The maximum payload size of a single frame.
+    /// - `bool` - Whether `permessage-deflate` was negotiated.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<(WebSocketFrame, usize)>, RequestError>` - The decoded frame and the
+    ///   number of bytes consumed, `None` if more data is needed, or an error if the frame
+    ///   violates the protocol.
+    #[inline(always)]
+    pub fn try_decode_ws_frame_with_deflate<D>(
+        data: D,
+        max_frame_size: usize,
+        is_deflate_enabled: bool,
+    ) -> Result<Option<(WebSocketFrame, usize)>, RequestError>
+    where
+        D: AsRef<[u8]>,
+    {
+        Self::try_decode_peer_ws_frame(data.as_ref(), max_frame_size, is_deflate_enabled, true)
+    }
+
+    /// Decodes and validates a server WebSocket frame received by a client.
+    ///
+    /// Applies the same checks as `try_decode_ws_frame_with_deflate`, except that frames
+    /// sent by a server must not be masked.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The raw data to decode into a WebSocket frame.
+    /// - `usize` - The maximum payload size of a single frame.
+    /// - `bool` - Whether `permessage-deflate` was negotiated.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<(WebSocketFrame, usize)>, RequestError>` - The decoded frame and the
+    ///   number of bytes consumed, `None` if more data is needed, or an error if the frame
+    ///   violates the protocol.
+    #[inline(always)]
+    pub fn try_decode_server_ws_frame<D>(
+        data: D,
+        max_frame_size: usize,
+        is_deflate_enabled: bool,
+    ) -> Result<Option<(WebSocketFrame, usize)>, RequestError>
+    where
+        D: AsRef<[u8]>,
+    {
+        Self::try_decode_peer_ws_frame(data.as_ref(), max_frame_size, is_deflate_enabled, false)
+    }
+
+    /// Decodes and validates a frame received from a peer.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw data to decode into a WebSocket frame.
+    /// - `usize` - The maximum payload size of a single frame.
+    /// - `bool` - Whether `permessage-deflate` was negotiated.
+    /// - `bool` - Whether the peer is a client, whose frames must be masked.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<(WebSocketFrame, usize)>, RequestError>` - The decoded frame and the
+    ///   number of bytes consumed, `None` if more data is needed, or an error if the frame
+    ///   violates the protocol.
+    fn try_decode_peer_ws_frame(
+        data_ref: &[u8],
+        max_frame_size: usize,
+        is_deflate_enabled: bool,
+        is_client_frame: bool,
+    ) -> Result<Option<(WebSocketFrame, usize)>, RequestError> {
+        let (first_byte, second_byte, payload_len, header_len): (u8, u8, u64, usize) =
+            match Self::parse_frame_header(data_ref) {
+                Some(header) => header,
+                None => return Ok(None),
+            };
+        let opcode: WebSocketOpcode = WebSocketOpcode::from_u8(first_byte & 0b0000_1111);
+        let is_rsv1_allowed: bool = is_deflate_enabled && (opcode.is_text() || opcode.is_binary());
+        let rsv_mask: u8 = if is_rsv1_allowed {
+            0b0011_0000
+        } else {
+            0b0111_0000
+        };
+        if (first_byte & rsv_mask) != 0 {
+            return Err(RequestError::WebSocketInvalidExtension(
+                HttpStatus::BadRequest,
+            ));
+        }
+        if opcode.is_reserved() {
+            return Err(RequestError::WebSocketOpcodeUnsupported(
+                HttpStatus::NotImplemented,
+            ));
+        }
+        if opcode.is_control() {
+            if (first_byte & 0b1000_0000) == 0 {
+                return Err(RequestError::WebSocketInvalidFragmentation(
+                    HttpStatus::BadRequest,
+                ));
             }
-            payload_len = u16::from_be_bytes(data_ref[index..index + 2].try_into().ok()?) as usize;
-            index += 2;
-        } else if payload_len == 127 {
-            if data_ref.len() < index + 8 {
-                return None;
+            if payload_len > 125 {
+                return Err(RequestError::WebSocketFrameTooLarge(HttpStatus::BadRequest));
             }
-            payload_len = u64::from_be_bytes(data_ref[index..index + 8].try_into().ok()?) as usize;
-            index += 8;
         }
-        let mask_key: Option<[u8; 4]> = if mask {
-            if data_ref.len() < index + 4 {
-                return None;
-            }
-            let key: [u8; 4] = data_ref[index..index + 4].try_into().ok()?;
-            index += 4;
-            Some(key)
+        let is_masked: bool = (second_byte & 0b1000_0000) != 0;
+        if is_client_frame && !is_masked {
+            return Err(RequestError::WebSocketMaskMissing(HttpStatus::BadRequest));
+        }
+        if !is_client_frame && is_masked {
+            return Err(RequestError::WebSocketUnexpectedMask(
+                HttpStatus::BadRequest,
+            ));
+        }
+        let payload_len: usize = usize::try_from(payload_len)
+            .ok()
+            .filter(|len: &usize| {
+                *len <= max_frame_size || max_frame_size == DEFAULT_LOW_SECURITY_MAX_BODY_SIZE
+            })
+            .ok_or(RequestError::WebSocketFrameTooLarge(
+                HttpStatus::PayloadTooLarge,
+            ))?;
+        let frame_len: usize =
+            header_len
+                .checked_add(payload_len)
+                .ok_or(RequestError::WebSocketFrameTooLarge(
+                    HttpStatus::PayloadTooLarge,
+                ))?;
+        if data_ref.len() < frame_len {
+            return Ok(None);
+        }
+        let frame: WebSocketFrame =
+            Self::build_frame(data_ref, first_byte, second_byte, header_len, payload_len);
+        if frame.is_close_opcode() {
+            Self::validate_close_payload(frame.get_payload_data())?;
+        }
+        Ok(Some((frame, frame_len)))
+    }
+
+    /// Encodes a single WebSocket frame.
+    ///
+    /// The payload length is written using the 7-bit, 16-bit or 64-bit form as required.
+    /// When a mask key is given, the mask bit is set and the payload is masked, as required
+    /// for frames sent by a client.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether this is the final frame of a message.
+    /// - `WebSocketOpcode` - The frame opcode.
+    /// - `Option<[u8; 4]>` - The mask key, if the frame is masked.
+    /// - `AsRef<[u8]>` - The unmasked payload data.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseBody` - The encoded frame bytes.
+    #[inline(always)]
+    pub fn encode_ws_frame<D>(
+        fin: bool,
+        opcode: WebSocketOpcode,
+        mask_key: Option<[u8; 4]>,
+        payload: D,
+    ) -> ResponseBody
+    where
+        D: AsRef<[u8]>,
+    {
+        Self::encode_ws_frame_with_rsv1(fin, false, opcode, mask_key, payload)
+    }
+
+    /// Encodes a single WebSocket frame with the given RSV1 flag.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether this is the final frame of a message.
+    /// - `bool` - Whether the RSV1 bit is set, marking the first frame of a compressed message.
+    /// - `WebSocketOpcode` - The frame opcode.
+    /// - `Option<[u8; 4]>` - The mask key, if the frame is masked.
+    /// - `AsRef<[u8]>` - The unmasked payload data.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseBody` - The encoded frame bytes.
+    pub(crate) fn encode_ws_frame_with_rsv1<D>(
+        fin: bool,
+        rsv1: bool,
+        opcode: WebSocketOpcode,
+        mask_key: Option<[u8; 4]>,
+        payload: D,
+    ) -> ResponseBody
+    where
+        D: AsRef<[u8]>,
+    {
+        let payload_ref: &[u8] = payload.as_ref();
+        let payload_len: usize = payload_ref.len();
+        let mut frame: ResponseBody = Vec::with_capacity(payload_len + 14);
+        let fin_bit: u8 = if fin { 0b1000_0000 } else { 0 };
+        let rsv1_bit: u8 = if rsv1 { 0b0100_0000 } else { 0 };
+        frame.push(fin_bit | rsv1_bit | (opcode.to_u8() & 0b0000_1111));
+        let mask_bit: u8 = if mask_key.is_some() { 0b1000_0000 } else { 0 };
+        if payload_len < 126 {
+            frame.push(mask_bit | payload_len as u8);
+        } else if payload_len <= u16::MAX as usize {
+            frame.push(mask_bit | 126);
+            frame.extend_from_slice(&(payload_len as u16).to_be_bytes());
         } else {
-            None
-        };
-        if data_ref.len() < index + payload_len {
-            return None;
+            frame.push(mask_bit | 127);
+            frame.extend_from_slice(&(payload_len as u64).to_be_bytes());
         }
-        let mut payload: Vec<u8> = data_ref[index..index + payload_len].to_vec();
-        if let Some(mask_key) = mask_key {
-            for (i, byte) in payload.iter_mut().enumerate() {
-                *byte ^= mask_key[i % 4];
+        match mask_key {
+            Some(mask_key) => {
+                frame.extend_from_slice(&mask_key);
+                frame.extend(
+                    payload_ref
+                        .iter()
+                        .enumerate()
+                        .map(|(i, byte): (usize, &u8)| byte ^ mask_key[i % 4]),
+                );
             }
+            None => frame.extend_from_slice(payload_ref),
         }
-        index += payload_len;
-        let frame: WebSocketFrame = WebSocketFrame {
-            fin,
-            opcode,
-            mask,
-            payload_data: payload,
-        };
-        Some((frame, index))
+        frame
+    }
+
+    /// Truncates a control frame payload to the 125 bytes allowed by RFC 6455.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The payload data.
+    ///
+    /// # Returns
+    ///
+    /// - `&[u8]` - The payload limited to 125 bytes.
+    #[inline(always)]
+    fn truncate_control_payload(payload: &[u8]) -> &[u8] {
+        &payload[..payload.len().min(125)]
+    }
+
+    /// Creates a Ping frame.
+    ///
+    /// Payloads longer than 125 bytes are truncated.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The application data carried by the ping.
+    /// - `Option<[u8; 4]>` - The mask key, if the frame is sent by a client.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseBody` - The encoded Ping frame.
+    #[inline(always)]
+    pub fn create_ping_frame<D>(payload: D, mask_key: Option<[u8; 4]>) -> ResponseBody
+    where
+        D: AsRef<[u8]>,
+    {
+        let payload: &[u8] = Self::truncate_control_payload(payload.as_ref());
+        Self::encode_ws_frame(true, WebSocketOpcode::Ping, mask_key, payload)
+    }
+
+    /// Creates a Pong frame.
+    ///
+    /// Payloads longer than 125 bytes are truncated.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The application data echoed from the ping.
+    /// - `Option<[u8; 4]>` - The mask key, if the frame is sent by a client.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseBody` - The encoded Pong frame.
+    #[inline(always)]
+    pub fn create_pong_frame<D>(payload: D, mask_key: Option<[u8; 4]>) -> ResponseBody
+    where
+        D: AsRef<[u8]>,
+    {
+        let payload: &[u8] = Self::truncate_control_payload(payload.as_ref());
+        Self::encode_ws_frame(true, WebSocketOpcode::Pong, mask_key, payload)
+    }
+
+    /// Creates a Close frame with a status code and reason.
+    ///
+    /// The reason is truncated on a character boundary so the payload fits in 125 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    /// - `AsRef<str>` - The close reason.
+    /// - `Option<[u8; 4]>` - The mask key, if the frame is sent by a client.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseBody` - The encoded Close frame.
+    pub fn create_close_frame<R>(code: u16, reason: R, mask_key: Option<[u8; 4]>) -> ResponseBody
+    where
+        R: AsRef<str>,
+    {
+        let reason: &str = reason.as_ref();
+        let mut reason_len: usize = reason.len().min(123);
+        while !reason.is_char_boundary(reason_len) {
+            reason_len -= 1;
+        }
+        let mut payload: Vec<u8> = Vec::with_capacity(2 + reason_len);
+        payload.extend_from_slice(&code.to_be_bytes());
+        payload.extend_from_slice(&reason.as_bytes()[..reason_len]);
+        Self::encode_ws_frame(true, WebSocketOpcode::Close, mask_key, payload)
     }
 
     /// Creates a list of response frames from the provided body.
@@ -266,27 +673,18 @@ impl WebSocketFrame {
                     frame_size = remaining.min(MAX_FRAME_SIZE);
                 }
             }
-            let mut frame: ResponseBody = Vec::with_capacity(frame_size + 10);
             let opcode: WebSocketOpcode = if is_first_frame {
                 base_opcode
             } else {
                 WebSocketOpcode::Continuation
             };
-            let fin: u8 = if remaining > frame_size { 0x00 } else { 0x80 };
-            let opcode_byte: u8 = opcode.to_u8() & 0x0F;
-            frame.push(fin | opcode_byte);
-            if frame_size < 126 {
-                frame.push(frame_size as u8);
-            } else if frame_size <= MAX_FRAME_SIZE {
-                frame.push(126);
-                frame.extend_from_slice(&(frame_size as u16).to_be_bytes());
-            } else {
-                frame.push(127);
-                frame.extend_from_slice(&(frame_size as u16).to_be_bytes());
-            }
             let end: usize = offset + frame_size;
-            frame.extend_from_slice(&data_ref[offset..end]);
-            frames_list.push(frame);
+            frames_list.push(Self::encode_ws_frame(
+                remaining == frame_size,
+                opcode,
+                None,
+                &data_ref[offset..end],
+            ));
             offset = end;
             is_first_frame = false;
         }
@@ -418,6 +816,34 @@ impl WebSocketFrame {
         Self::base64_encode(hash)
     }
 
+    /// Generates a random `Sec-WebSocket-Key` for a client handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The base64 encoding of 16 random bytes.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the operating system's random source is unavailable.
+    #[inline(always)]
+    pub fn generate_key() -> String {
+        Self::base64_encode(random_bytes::<16>())
+    }
+
+    /// Generates a random mask key for a frame sent by a client.
+    ///
+    /// # Returns
+    ///
+    /// - `[u8; 4]` - The mask key.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the operating system's random source is unavailable.
+    #[inline(always)]
+    pub fn generate_mask_key() -> [u8; 4] {
+        random_bytes()
+    }
+
     /// Encodes the input data as a base64 string, returning an `Option<String>`.
     ///
     /// # Arguments
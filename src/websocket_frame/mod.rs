@@ -1,5 +1,7 @@
 mod r#enum;
 mod r#impl;
 mod r#struct;
+#[cfg(test)]
+mod test;
 
 pub use {r#enum::*, r#struct::*};
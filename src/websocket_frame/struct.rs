@@ -5,6 +5,8 @@ use crate::*;
 pub struct WebSocketFrame {
     /// FIN flag indicating if this is the final frame.
     pub(super) fin: bool,
+    /// RSV1 flag, set on the first frame of a compressed message.
+    pub(super) rsv1: bool,
     /// Opcode indicating the frame type (text, binary, etc.).
     pub(super) opcode: WebSocketOpcode,
     /// Mask flag indicating if the payload is masked.
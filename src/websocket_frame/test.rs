@@ -0,0 +1,225 @@
+use crate::*;
+
+const MASK_KEY: Option<[u8; 4]> = Some([0x12, 0x34, 0x56, 0x78]);
+
+#[test]
+fn try_decode_ws_frame_masked_text() {
+    let data: Vec<u8> =
+        WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Text, MASK_KEY, b"hello");
+    let (frame, consumed): (WebSocketFrame, usize) =
+        WebSocketFrame::try_decode_ws_frame(&data, MAX_FRAME_SIZE)
+            .unwrap()
+            .unwrap();
+    assert_eq!(consumed, data.len());
+    assert!(frame.get_fin());
+    assert!(frame.is_text_opcode());
+    assert_eq!(frame.get_payload_data(), b"hello");
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(&data[..data.len() - 1], MAX_FRAME_SIZE),
+        Ok(None)
+    );
+}
+
+#[test]
+fn try_decode_ws_frame_protocol_errors() {
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame([0x81, 0x00], MAX_FRAME_SIZE),
+        Err(RequestError::WebSocketMaskMissing(HttpStatus::BadRequest))
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame_with_rsv1(
+                true,
+                true,
+                WebSocketOpcode::Text,
+                MASK_KEY,
+                b"x"
+            ),
+            MAX_FRAME_SIZE
+        ),
+        Err(RequestError::WebSocketInvalidExtension(
+            HttpStatus::BadRequest
+        ))
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Reserved(0x3), MASK_KEY, b"x"),
+            MAX_FRAME_SIZE
+        ),
+        Err(RequestError::WebSocketOpcodeUnsupported(
+            HttpStatus::NotImplemented
+        ))
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(false, WebSocketOpcode::Ping, MASK_KEY, b"x"),
+            MAX_FRAME_SIZE
+        ),
+        Err(RequestError::WebSocketInvalidFragmentation(
+            HttpStatus::BadRequest
+        ))
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Ping, MASK_KEY, [0; 126]),
+            MAX_FRAME_SIZE
+        ),
+        Err(RequestError::WebSocketFrameTooLarge(HttpStatus::BadRequest))
+    );
+}
+
+#[test]
+fn try_decode_ws_frame_size_limit() {
+    let mut header: Vec<u8> = vec![0x82, 0x80 | 127];
+    header.extend_from_slice(&u64::MAX.to_be_bytes());
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(&header, MAX_FRAME_SIZE),
+        Err(RequestError::WebSocketFrameTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Binary, MASK_KEY, [0; 10]),
+            9
+        ),
+        Err(RequestError::WebSocketFrameTooLarge(
+            HttpStatus::PayloadTooLarge
+        ))
+    );
+    assert_eq!(WebSocketFrame::decode_ws_frame(&header), None);
+}
+
+#[test]
+fn try_decode_ws_frame_close_payload() {
+    let mut payload: Vec<u8> = 1000u16.to_be_bytes().to_vec();
+    payload.extend_from_slice(b"bye");
+    assert!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Close, MASK_KEY, &payload),
+            MAX_FRAME_SIZE
+        )
+        .is_ok()
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Close, MASK_KEY, [0x03]),
+            MAX_FRAME_SIZE
+        ),
+        Err(RequestError::WebSocketInvalidCloseCode(
+            HttpStatus::BadRequest
+        ))
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(
+                true,
+                WebSocketOpcode::Close,
+                MASK_KEY,
+                1005u16.to_be_bytes()
+            ),
+            MAX_FRAME_SIZE
+        ),
+        Err(RequestError::WebSocketInvalidCloseCode(
+            HttpStatus::BadRequest
+        ))
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_ws_frame(
+            WebSocketFrame::encode_ws_frame(
+                true,
+                WebSocketOpcode::Close,
+                MASK_KEY,
+                [0x03, 0xE8, 0xFF]
+            ),
+            MAX_FRAME_SIZE
+        ),
+        Err(RequestError::WebSocketInvalidUtf8(HttpStatus::BadRequest))
+    );
+}
+
+#[test]
+fn encode_ws_frame_lengths() {
+    let short: Vec<u8> =
+        WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Binary, None, [0; 125]);
+    assert_eq!(&short[..2], &[0x82, 125]);
+    let medium: Vec<u8> =
+        WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Binary, None, [0; 126]);
+    assert_eq!(&medium[..4], &[0x82, 126, 0x00, 126]);
+    let long_payload: Vec<u8> = vec![0; 65536];
+    let long: Vec<u8> =
+        WebSocketFrame::encode_ws_frame(false, WebSocketOpcode::Binary, None, &long_payload);
+    assert_eq!(&long[..2], &[0x02, 127]);
+    assert_eq!(&long[2..10], &65536u64.to_be_bytes());
+    let (frame, consumed): (WebSocketFrame, usize) =
+        WebSocketFrame::decode_ws_frame(&long).unwrap();
+    assert_eq!(consumed, long.len());
+    assert_eq!(frame.get_payload_data().len(), 65536);
+}
+
+#[test]
+fn encode_ws_frame_masked_round_trip() {
+    let mask_key: [u8; 4] = [1, 2, 3, 4];
+    let data: Vec<u8> =
+        WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Text, Some(mask_key), "hello");
+    assert_eq!(data[1], 0x80 | 5);
+    assert_eq!(&data[2..6], &mask_key);
+    let (frame, _): (WebSocketFrame, usize) =
+        WebSocketFrame::try_decode_ws_frame(&data, MAX_FRAME_SIZE)
+            .unwrap()
+            .unwrap();
+    assert!(frame.get_mask());
+    assert_eq!(frame.get_payload_data(), b"hello");
+}
+
+#[test]
+fn create_control_frames() {
+    assert_eq!(
+        WebSocketFrame::create_ping_frame([], None),
+        PING_FRAME.to_vec()
+    );
+    assert_eq!(
+        WebSocketFrame::create_pong_frame(b"abc", None),
+        vec![0x8A, 3, b'a', b'b', b'c']
+    );
+    assert_eq!(WebSocketFrame::create_ping_frame([0; 200], None).len(), 127);
+    let close: Vec<u8> = WebSocketFrame::create_close_frame(1000, "done", None);
+    assert_eq!(close, vec![0x88, 6, 0x03, 0xE8, b'd', b'o', b'n', b'e']);
+    let long_reason: String = "é".repeat(100);
+    let close: Vec<u8> = WebSocketFrame::create_close_frame(1001, &long_reason, None);
+    assert_eq!(close[1], 124);
+    assert!(std::str::from_utf8(&close[4..]).is_ok());
+}
+
+#[test]
+fn create_frame_list_splits_large_payload() {
+    let data: Vec<u8> = vec![b'a'; MAX_FRAME_SIZE + 10];
+    let frames: Vec<ResponseBody> = WebSocketFrame::create_frame_list(&data);
+    assert_eq!(frames.len(), 2);
+    assert_eq!(&frames[0][..4], &[0x01, 126, 0xFF, 0xFF]);
+    assert_eq!(&frames[1][..2], &[0x80, 10]);
+}
+
+#[test]
+fn try_decode_server_ws_frame_rejects_mask() {
+    let unmasked: ResponseBody =
+        WebSocketFrame::encode_ws_frame(true, WebSocketOpcode::Binary, None, [1, 2]);
+    let (frame, consumed): (WebSocketFrame, usize) =
+        WebSocketFrame::try_decode_server_ws_frame(&unmasked, 1024, false)
+            .unwrap()
+            .unwrap();
+    assert_eq!(frame.get_payload_data(), &vec![1, 2]);
+    assert_eq!(consumed, unmasked.len());
+    let masked: ResponseBody = WebSocketFrame::encode_ws_frame(
+        true,
+        WebSocketOpcode::Binary,
+        Some(WebSocketFrame::generate_mask_key()),
+        [1, 2],
+    );
+    assert_eq!(
+        WebSocketFrame::try_decode_server_ws_frame(&masked, 1024, false),
+        Err(RequestError::WebSocketUnexpectedMask(
+            HttpStatus::BadRequest
+        ))
+    );
+}
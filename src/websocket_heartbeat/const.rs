@@ -0,0 +1,11 @@
+/// Timeout value that disables a WebSocket heartbeat timer.
+pub const WEBSOCKET_TIMEOUT_DISABLED: u64 = u64::MAX;
+
+/// Default time without received frames before a ping is sent, in milliseconds.
+pub const DEFAULT_WEBSOCKET_PING_INTERVAL_MS: u64 = 30_000;
+
+/// Default time to wait for the pong answering a ping, in milliseconds.
+pub const DEFAULT_WEBSOCKET_PONG_TIMEOUT_MS: u64 = 10_000;
+
+/// Default time without received frames before the connection is closed, in milliseconds.
+pub const DEFAULT_WEBSOCKET_MAX_IDLE_MS: u64 = WEBSOCKET_TIMEOUT_DISABLED;
@@ -0,0 +1,214 @@
+use crate::*;
+
+impl Default for WebSocketConfig {
+    /// Creates a new `WebSocketConfig` with the default heartbeat policy.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - Pings after 30 seconds of silence, a 10 second pong deadline and no idle limit.
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            ping_interval_ms: DEFAULT_WEBSOCKET_PING_INTERVAL_MS,
+            pong_timeout_ms: DEFAULT_WEBSOCKET_PONG_TIMEOUT_MS,
+            max_idle_ms: DEFAULT_WEBSOCKET_MAX_IDLE_MS,
+            unsolicited_pong_liveness: true,
+        }
+    }
+}
+
+impl WebSocketConfig {
+    /// Creates a new `WebSocketConfig` from a JSON string.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<WebSocketConfig, serde_json::Error>` - The parsed `WebSocketConfig` or an error.
+    pub fn from_json<C>(json: C) -> Result<WebSocketConfig, serde_json::Error>
+    where
+        C: AsRef<str>,
+    {
+        serde_json::from_str(json.as_ref())
+    }
+
+    /// Creates a `WebSocketConfig` that splits a read timeout between ping and pong.
+    ///
+    /// A ping is sent after half the timeout without frames and the pong must arrive
+    /// within the other half. This is the policy of `Request::ws_from_stream`.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The read timeout in milliseconds, or `DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS`
+    ///   for no timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketConfig` - The derived configuration.
+    pub fn from_read_timeout(read_timeout_ms: u64) -> Self {
+        let half_timeout_ms: u64 = if read_timeout_ms == DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS {
+            WEBSOCKET_TIMEOUT_DISABLED
+        } else {
+            read_timeout_ms.div_ceil(2)
+        };
+        Self {
+            ping_interval_ms: half_timeout_ms,
+            pong_timeout_ms: half_timeout_ms,
+            max_idle_ms: WEBSOCKET_TIMEOUT_DISABLED,
+            unsolicited_pong_liveness: true,
+        }
+    }
+}
+
+impl WebSocketHeartbeatStats {
+    /// Gets the average round-trip time.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The average, or `None` if no pong was received yet.
+    pub fn average_rtt(&self) -> Option<Duration> {
+        u32::try_from(self.pongs_received)
+            .ok()
+            .filter(|count: &u32| *count > 0)
+            .map(|count: u32| self.total_rtt / count)
+    }
+
+    /// Records a measured round-trip time.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - The round-trip time.
+    fn record_rtt(&mut self, rtt: Duration) {
+        self.pongs_received += 1;
+        self.last_rtt = Some(rtt);
+        self.min_rtt = Some(self.min_rtt.map_or(rtt, |min: Duration| min.min(rtt)));
+        self.max_rtt = Some(self.max_rtt.map_or(rtt, |max: Duration| max.max(rtt)));
+        self.total_rtt = self.total_rtt.saturating_add(rtt);
+    }
+}
+
+impl WebSocketHeartbeat {
+    /// Creates a new heartbeat that treats the connection as alive now.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketConfig` - The heartbeat and idle policy.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketHeartbeat` - The heartbeat state.
+    #[inline(always)]
+    pub fn new(config: WebSocketConfig) -> Self {
+        Self {
+            config,
+            stats: WebSocketHeartbeatStats::default(),
+            last_received: Instant::now(),
+            ping_sent_at: None,
+            ping_payload: [0; 8],
+        }
+    }
+
+    /// Adds a timeout in milliseconds to an instant unless it is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// - `Instant` - The start of the timeout.
+    /// - `u64` - The timeout in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Instant>` - The deadline, or `None` if the timeout is disabled.
+    fn get_deadline(start: Instant, timeout_ms: u64) -> Option<Instant> {
+        if timeout_ms == WEBSOCKET_TIMEOUT_DISABLED {
+            return None;
+        }
+        start.checked_add(Duration::from_millis(timeout_ms))
+    }
+
+    /// Gets the deadline of the pong or of the next ping, whichever applies.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Instant>` - The deadline, or `None` if no ping timer is active.
+    fn get_ping_deadline(&self) -> Option<Instant> {
+        match self.ping_sent_at {
+            Some(sent_at) => Self::get_deadline(sent_at, self.config.pong_timeout_ms),
+            None => Self::get_deadline(self.last_received, self.config.ping_interval_ms),
+        }
+    }
+
+    /// Gets how long the next read may wait before the heartbeat must act.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The time until the next deadline, or `None` to wait forever.
+    pub fn get_timeout(&self) -> Option<Duration> {
+        let idle_deadline: Option<Instant> =
+            Self::get_deadline(self.last_received, self.config.max_idle_ms);
+        let deadline: Instant = match (idle_deadline, self.get_ping_deadline()) {
+            (Some(idle), Some(ping)) => idle.min(ping),
+            (deadline, None) | (None, deadline) => deadline?,
+        };
+        Some(deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Handles an elapsed read timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<[u8; 8]>, RequestError>` - The payload of a ping to send, `None` if no
+    ///   deadline has passed yet, or `ReadTimeout` if the peer is idle or missed a pong.
+    pub fn on_timeout(&mut self) -> Result<Option<[u8; 8]>, RequestError> {
+        let now: Instant = Instant::now();
+        let is_expired = |deadline: Option<Instant>| deadline.is_some_and(|at: Instant| at <= now);
+        if is_expired(Self::get_deadline(
+            self.last_received,
+            self.config.max_idle_ms,
+        )) {
+            return Err(RequestError::ReadTimeout(HttpStatus::RequestTimeout));
+        }
+        if !is_expired(self.get_ping_deadline()) {
+            return Ok(None);
+        }
+        if self.ping_sent_at.is_some() {
+            return Err(RequestError::ReadTimeout(HttpStatus::RequestTimeout));
+        }
+        self.stats.pings_sent += 1;
+        self.ping_payload = self.stats.pings_sent.to_be_bytes();
+        self.ping_sent_at = Some(now);
+        Ok(Some(self.ping_payload))
+    }
+
+    /// Records a frame received from the peer.
+    ///
+    /// Every frame refreshes the idle timer. A pong carrying the payload of the
+    /// outstanding ping ends the wait and records the round-trip time. Other pongs only
+    /// count when `unsolicited_pong_liveness` is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketOpcode` - The opcode of the frame.
+    /// - `&[u8]` - The payload of the frame.
+    pub fn on_frame(&mut self, opcode: WebSocketOpcode, payload: &[u8]) {
+        let now: Instant = Instant::now();
+        if !opcode.is_pong() {
+            self.last_received = now;
+            return;
+        }
+        match self.ping_sent_at {
+            Some(sent_at) if payload == self.ping_payload => {
+                self.stats
+                    .record_rtt(now.saturating_duration_since(sent_at));
+                self.ping_sent_at = None;
+                self.last_received = now;
+            }
+            _ if self.config.unsolicited_pong_liveness => {
+                self.ping_sent_at = None;
+                self.last_received = now;
+            }
+            _ => {}
+        }
+    }
+}
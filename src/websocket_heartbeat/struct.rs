@@ -0,0 +1,71 @@
+use crate::*;
+
+/// Heartbeat and idle policy for WebSocket connections.
+///
+/// Timeouts equal to `WEBSOCKET_TIMEOUT_DISABLED` are not applied.
+#[derive(Clone, Copy, Data, Debug, Deserialize, DisplayDebug, Eq, New, PartialEq, Serialize)]
+#[serde(default)]
+pub struct WebSocketConfig {
+    /// Time without received frames before a ping is sent, in milliseconds.
+    #[get(type(copy))]
+    #[set]
+    pub(super) ping_interval_ms: u64,
+    /// Time to wait for the pong answering a ping before failing, in milliseconds.
+    #[get(type(copy))]
+    #[set]
+    pub(super) pong_timeout_ms: u64,
+    /// Time without received frames before failing, in milliseconds.
+    #[get(type(copy))]
+    #[set]
+    pub(super) max_idle_ms: u64,
+    /// Whether a pong that does not answer the outstanding ping counts as liveness.
+    #[get(type(copy))]
+    #[set]
+    pub(super) unsolicited_pong_liveness: bool,
+}
+
+/// Round-trip time statistics measured from ping and pong frames.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Getter, PartialEq, Serialize)]
+pub struct WebSocketHeartbeatStats {
+    /// Number of pings sent by the heartbeat.
+    #[get(type(copy))]
+    pub(super) pings_sent: u64,
+    /// Number of pongs that answered a ping sent by the heartbeat.
+    #[get(type(copy))]
+    pub(super) pongs_received: u64,
+    /// The most recent round-trip time.
+    #[get(type(copy))]
+    pub(super) last_rtt: Option<Duration>,
+    /// The smallest round-trip time measured.
+    #[get(type(copy))]
+    pub(super) min_rtt: Option<Duration>,
+    /// The largest round-trip time measured.
+    #[get(type(copy))]
+    pub(super) max_rtt: Option<Duration>,
+    /// The sum of all round-trip times measured.
+    #[get(type(copy))]
+    pub(super) total_rtt: Duration,
+}
+
+/// Heartbeat state of a single WebSocket connection.
+///
+/// Decides when to send pings, detects missing pongs and idle peers, and
+/// measures the round-trip time of each answered ping.
+#[derive(Clone, Copy, Debug, Getter)]
+pub struct WebSocketHeartbeat {
+    /// The heartbeat and idle policy.
+    #[get(type(copy))]
+    pub(super) config: WebSocketConfig,
+    /// The round-trip time statistics.
+    #[get(type(copy))]
+    pub(super) stats: WebSocketHeartbeatStats,
+    /// When a frame was last received from the peer.
+    #[get(type(copy))]
+    pub(super) last_received: Instant,
+    /// When the outstanding ping was sent, if a pong is awaited.
+    #[get(skip)]
+    pub(super) ping_sent_at: Option<Instant>,
+    /// The payload of the most recent ping, used to match its pong.
+    #[get(skip)]
+    pub(super) ping_payload: [u8; 8],
+}
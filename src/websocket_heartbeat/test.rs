@@ -0,0 +1,74 @@
+use crate::*;
+
+fn heartbeat_config(ping_interval_ms: u64, pong_timeout_ms: u64) -> WebSocketConfig {
+    let mut config: WebSocketConfig = WebSocketConfig::default();
+    config
+        .set_ping_interval_ms(ping_interval_ms)
+        .set_pong_timeout_ms(pong_timeout_ms);
+    config
+}
+
+#[test]
+fn websocket_config_from_read_timeout() {
+    let config: WebSocketConfig = WebSocketConfig::from_read_timeout(3001);
+    assert_eq!(config.get_ping_interval_ms(), 1501);
+    assert_eq!(config.get_pong_timeout_ms(), 1501);
+    assert_eq!(config.get_max_idle_ms(), WEBSOCKET_TIMEOUT_DISABLED);
+    let disabled: WebSocketConfig =
+        WebSocketConfig::from_read_timeout(DEFAULT_LOW_SECURITY_READ_TIMEOUT_MS);
+    assert_eq!(WebSocketHeartbeat::new(disabled).get_timeout(), None);
+    let parsed: WebSocketConfig =
+        WebSocketConfig::from_json(r#"{"ping_interval_ms": 500}"#).unwrap();
+    assert_eq!(parsed.get_ping_interval_ms(), 500);
+    assert_eq!(
+        parsed.get_pong_timeout_ms(),
+        DEFAULT_WEBSOCKET_PONG_TIMEOUT_MS
+    );
+}
+
+#[test]
+fn websocket_heartbeat_measures_rtt() {
+    let mut heartbeat: WebSocketHeartbeat = WebSocketHeartbeat::new(heartbeat_config(0, 1000));
+    let payload: [u8; 8] = heartbeat.on_timeout().unwrap().unwrap();
+    assert_eq!(heartbeat.get_stats().get_pings_sent(), 1);
+    assert_eq!(heartbeat.on_timeout(), Ok(None));
+    std::thread::sleep(Duration::from_millis(5));
+    heartbeat.on_frame(WebSocketOpcode::Pong, &payload);
+    let stats: WebSocketHeartbeatStats = heartbeat.get_stats();
+    assert_eq!(stats.get_pongs_received(), 1);
+    assert!(stats.get_last_rtt().unwrap() >= Duration::from_millis(5));
+    assert_eq!(stats.get_min_rtt(), stats.get_last_rtt());
+    assert_eq!(stats.average_rtt(), stats.get_last_rtt());
+}
+
+#[test]
+fn websocket_heartbeat_pong_deadline() {
+    let mut config: WebSocketConfig = heartbeat_config(0, 0);
+    config.set_unsolicited_pong_liveness(false);
+    let mut heartbeat: WebSocketHeartbeat = WebSocketHeartbeat::new(config);
+    assert!(heartbeat.on_timeout().unwrap().is_some());
+    heartbeat.on_frame(WebSocketOpcode::Pong, b"other");
+    heartbeat.on_frame(WebSocketOpcode::Text, b"data");
+    assert_eq!(
+        heartbeat.on_timeout(),
+        Err(RequestError::ReadTimeout(HttpStatus::RequestTimeout))
+    );
+    config.set_unsolicited_pong_liveness(true);
+    let mut heartbeat: WebSocketHeartbeat = WebSocketHeartbeat::new(config);
+    assert!(heartbeat.on_timeout().unwrap().is_some());
+    heartbeat.on_frame(WebSocketOpcode::Pong, b"other");
+    assert_eq!(heartbeat.get_stats().get_pongs_received(), 0);
+    assert!(heartbeat.on_timeout().unwrap().is_some());
+}
+
+#[test]
+fn websocket_heartbeat_max_idle() {
+    let mut config: WebSocketConfig = heartbeat_config(WEBSOCKET_TIMEOUT_DISABLED, 1000);
+    config.set_max_idle_ms(0);
+    let mut heartbeat: WebSocketHeartbeat = WebSocketHeartbeat::new(config);
+    assert_eq!(heartbeat.get_timeout(), Some(Duration::ZERO));
+    assert_eq!(
+        heartbeat.on_timeout(),
+        Err(RequestError::ReadTimeout(HttpStatus::RequestTimeout))
+    );
+}
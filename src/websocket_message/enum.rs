@@ -0,0 +1,21 @@
+use crate::*;
+
+/// Represents a complete WebSocket message received from a peer.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum WebSocketMessage {
+    /// A text message containing valid UTF-8.
+    Text(String),
+    /// A binary message.
+    Binary(Vec<u8>),
+    /// A ping control frame with its application data.
+    Ping(Vec<u8>),
+    /// A pong control frame with its application data.
+    Pong(Vec<u8>),
+    /// A close control frame with its status code and reason.
+    Close {
+        /// The close status code, or 1005 if the frame carried no status code.
+        code: u16,
+        /// The close reason.
+        reason: String,
+    },
+}
@@ -0,0 +1,530 @@
+use crate::*;
+
+impl WebSocketMessage {
+    /// Creates a message from a complete data or control payload.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketOpcode` - The opcode of the message.
+    /// - `Vec<u8>` - The unmasked payload, already validated by the decoder.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<WebSocketMessage>` - The message, or `None` for continuation and reserved opcodes.
+    pub(crate) fn from_payload(opcode: WebSocketOpcode, payload: Vec<u8>) -> Option<Self> {
+        match opcode {
+            WebSocketOpcode::Text => {
+                Some(Self::Text(String::from_utf8_lossy(&payload).into_owned()))
+            }
+            WebSocketOpcode::Binary => Some(Self::Binary(payload)),
+            WebSocketOpcode::Ping => Some(Self::Ping(payload)),
+            WebSocketOpcode::Pong => Some(Self::Pong(payload)),
+            WebSocketOpcode::Close => {
+                if payload.len() < 2 {
+                    return Some(Self::Close {
+                        code: 1005,
+                        reason: String::new(),
+                    });
+                }
+                Some(Self::Close {
+                    code: u16::from_be_bytes([payload[0], payload[1]]),
+                    reason: String::from_utf8_lossy(&payload[2..]).into_owned(),
+                })
+            }
+            WebSocketOpcode::Continuation | WebSocketOpcode::Reserved(_) => None,
+        }
+    }
+
+    /// Encodes the message into the frames to send to a peer.
+    ///
+    /// Text and binary messages are split into frames of at most `MAX_FRAME_SIZE` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<[u8; 4]>` - The mask key, if the frames are sent by a client.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<ResponseBody>` - The encoded frames.
+    pub fn to_frame_list(&self, mask_key: Option<[u8; 4]>) -> Vec<ResponseBody> {
+        let (opcode, payload): (WebSocketOpcode, &[u8]) = match self {
+            Self::Text(text) => (WebSocketOpcode::Text, text.as_bytes()),
+            Self::Binary(data) => (WebSocketOpcode::Binary, data),
+            Self::Ping(data) => return vec![WebSocketFrame::create_ping_frame(data, mask_key)],
+            Self::Pong(data) => return vec![WebSocketFrame::create_pong_frame(data, mask_key)],
+            Self::Close { code, reason } => {
+                return vec![WebSocketFrame::create_close_frame(*code, reason, mask_key)];
+            }
+        };
+        Self::encode_data_frames(opcode, false, mask_key, payload)
+    }
+
+    /// Encodes the message into frames compressed with the `permessage-deflate` extension.
+    ///
+    /// Text and binary payloads are compressed and the first frame is marked with RSV1.
+    /// Control messages are never compressed.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<[u8; 4]>` - The mask key, if the frames are sent by a client.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<ResponseBody>` - The encoded frames.
+    pub fn to_deflate_frame_list(&self, mask_key: Option<[u8; 4]>) -> Vec<ResponseBody> {
+        let (opcode, payload): (WebSocketOpcode, &[u8]) = match self {
+            Self::Text(text) => (WebSocketOpcode::Text, text.as_bytes()),
+            Self::Binary(data) => (WebSocketOpcode::Binary, data),
+            _ => return self.to_frame_list(mask_key),
+        };
+        let compressed: Vec<u8> = WebSocketDeflateConfig::compress(payload);
+        Self::encode_data_frames(opcode, true, mask_key, &compressed)
+    }
+
+    /// Splits a data payload into frames of at most `MAX_FRAME_SIZE` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketOpcode` - The opcode of the first frame.
+    /// - `bool` - The RSV1 bit of the first frame.
+    /// - `Option<[u8; 4]>` - The mask key, if the frames are sent by a client.
+    /// - `&[u8]` - The payload to send.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<ResponseBody>` - The encoded frames.
+    fn encode_data_frames(
+        opcode: WebSocketOpcode,
+        rsv1: bool,
+        mask_key: Option<[u8; 4]>,
+        payload: &[u8],
+    ) -> Vec<ResponseBody> {
+        if payload.is_empty() {
+            return vec![WebSocketFrame::encode_ws_frame_with_rsv1(
+                true, rsv1, opcode, mask_key, payload,
+            )];
+        }
+        let chunk_count: usize = payload.len().div_ceil(MAX_FRAME_SIZE);
+        payload
+            .chunks(MAX_FRAME_SIZE)
+            .enumerate()
+            .map(|(index, chunk): (usize, &[u8])| {
+                let (frame_opcode, frame_rsv1): (WebSocketOpcode, bool) = if index == 0 {
+                    (opcode, rsv1)
+                } else {
+                    (WebSocketOpcode::Continuation, false)
+                };
+                WebSocketFrame::encode_ws_frame_with_rsv1(
+                    index + 1 == chunk_count,
+                    frame_rsv1,
+                    frame_opcode,
+                    mask_key,
+                    chunk,
+                )
+            })
+            .collect()
+    }
+
+    /// Checks whether the message is a close message.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the message is `Close`.
+    #[inline(always)]
+    pub fn is_close(&self) -> bool {
+        matches!(self, Self::Close { .. })
+    }
+}
+
+impl<R, W> WebSocketReader<R, W>
+where
+    R: AsyncReadHalf,
+    W: AsyncWriteHalf,
+{
+    /// Creates a new `WebSocketReader` for an upgraded connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The upgraded stream.
+    /// - `RequestConfig` - Configuration providing the buffer size and WebSocket size limits.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketReader<R, W>` - A reader with an empty buffer.
+    #[inline(always)]
+    pub fn new(stream: ArcRwLockStream<R, W>, config: RequestConfig) -> Self {
+        Self {
+            stream,
+            config,
+            buffer: Vec::with_capacity(config.get_buffer_size()),
+            message: Vec::new(),
+            message_opcode: None,
+            message_compressed: false,
+            deflate: None,
+            heartbeat: WebSocketHeartbeat::new(WebSocketConfig::default()),
+            is_client: false,
+            close_sent: false,
+            close_received: false,
+        }
+    }
+
+    /// Creates a new `WebSocketReader` that starts with bytes already read from the stream.
+    ///
+    /// This is used with `ConnectionReader::take_buffer` when frames arrive together
+    /// with the upgrade request.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The upgraded stream.
+    /// - `RequestConfig` - Configuration providing the buffer size and WebSocket size limits.
+    /// - `Vec<u8>` - The bytes read past the end of the upgrade request.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketReader<R, W>` - A reader starting with the given bytes.
+    #[inline(always)]
+    pub fn with_buffer(
+        stream: ArcRwLockStream<R, W>,
+        config: RequestConfig,
+        buffer: Vec<u8>,
+    ) -> Self {
+        let mut reader: Self = Self::new(stream, config);
+        reader.buffer = buffer;
+        reader
+    }
+
+    /// Creates a new `WebSocketReader` for the client side of an upgraded connection.
+    ///
+    /// Frames from the server must be unmasked and every frame sent is masked with a
+    /// fresh random key.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockStream<R, W>` - The upgraded stream.
+    /// - `RequestConfig` - Configuration providing the buffer size and WebSocket size limits.
+    /// - `Vec<u8>` - The bytes read past the end of the handshake response.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketReader<R, W>` - A client reader starting with the given bytes.
+    #[inline(always)]
+    pub fn client(stream: ArcRwLockStream<R, W>, config: RequestConfig, buffer: Vec<u8>) -> Self {
+        let mut reader: Self = Self::with_buffer(stream, config, buffer);
+        reader.is_client = true;
+        reader
+    }
+
+    /// Enables the negotiated `permessage-deflate` extension for received and sent messages.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketDeflateConfig` - The configuration agreed during the handshake.
+    #[inline(always)]
+    pub fn set_deflate(&mut self, deflate: WebSocketDeflateConfig) {
+        self.deflate = Some(deflate);
+    }
+
+    /// Sets the heartbeat and idle policy, restarting the heartbeat timers.
+    ///
+    /// # Arguments
+    ///
+    /// - `WebSocketConfig` - The heartbeat and idle policy.
+    #[inline(always)]
+    pub fn set_ws_config(&mut self, ws_config: WebSocketConfig) {
+        self.heartbeat = WebSocketHeartbeat::new(ws_config);
+    }
+
+    /// Gets the round-trip time statistics measured by the heartbeat.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketHeartbeatStats` - The statistics of this connection.
+    #[inline(always)]
+    pub fn get_heartbeat_stats(&self) -> WebSocketHeartbeatStats {
+        self.heartbeat.get_stats()
+    }
+
+    /// Gets the mask key for the next frame sent on this connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<[u8; 4]>` - A random key on the client side, `None` on the server side.
+    #[inline(always)]
+    fn get_mask_key(&self) -> Option<[u8; 4]> {
+        self.is_client.then(WebSocketFrame::generate_mask_key)
+    }
+
+    /// Sends a message to the peer.
+    ///
+    /// Text and binary messages are compressed when `permessage-deflate` is enabled.
+    /// Sending a close message starts the close handshake like `close`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&WebSocketMessage` - The message to send.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the message was sent.
+    pub async fn send_message(&mut self, message: &WebSocketMessage) -> Result<(), RequestError> {
+        if let WebSocketMessage::Close { code, reason } = message {
+            return self.close(*code, reason).await;
+        }
+        let mask_key: Option<[u8; 4]> = self.get_mask_key();
+        let frame_list: Vec<ResponseBody> = if self.deflate.is_some() {
+            message.to_deflate_frame_list(mask_key)
+        } else {
+            message.to_frame_list(mask_key)
+        };
+        self.stream.try_send_body_list(frame_list).await?;
+        self.stream.try_flush().await?;
+        Ok(())
+    }
+
+    /// Checks whether the close handshake has finished in both directions.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether close frames were both sent and received.
+    #[inline(always)]
+    pub fn is_closed(&self) -> bool {
+        self.close_sent && self.close_received
+    }
+
+    /// Sends a close frame to start the close handshake.
+    ///
+    /// Messages may still be received until the peer answers with its own close frame.
+    /// Calling this again after a close frame was sent has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The close status code.
+    /// - `AsRef<str>` - The close reason.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the close frame was sent or already sent.
+    pub async fn close<S>(&mut self, code: u16, reason: S) -> Result<(), RequestError>
+    where
+        S: AsRef<str>,
+    {
+        if self.close_sent {
+            return Ok(());
+        }
+        self.close_sent = true;
+        self.stream
+            .try_send_body(WebSocketFrame::create_close_frame(
+                code,
+                reason,
+                self.get_mask_key(),
+            ))
+            .await?;
+        self.stream.try_flush().await?;
+        Ok(())
+    }
+
+    /// Gets the close status code used to fail the connection for an error.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RequestError` - The protocol error.
+    ///
+    /// # Returns
+    ///
+    /// - `u16` - The close status code.
+    fn get_error_close_code(error: &RequestError) -> u16 {
+        match error {
+            RequestError::WebSocketInvalidUtf8(_) | RequestError::WebSocketPayloadCorrupted(_) => {
+                1007
+            }
+            RequestError::WebSocketFrameTooLarge(_) => 1009,
+            RequestError::ReadTimeout(_) => 1001,
+            _ => 1002,
+        }
+    }
+
+    /// Decodes the next message from the buffered bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<WebSocketMessage>, RequestError>` - The next message, `None` if more
+    ///   data is needed, or a protocol error.
+    fn decode_message(&mut self) -> Result<Option<WebSocketMessage>, RequestError> {
+        loop {
+            let max_frame_size: usize = self.config.get_max_ws_frame_size();
+            let decoded: Option<(WebSocketFrame, usize)> = if self.is_client {
+                WebSocketFrame::try_decode_server_ws_frame(
+                    &self.buffer,
+                    max_frame_size,
+                    self.deflate.is_some(),
+                )?
+            } else {
+                WebSocketFrame::try_decode_ws_frame_with_deflate(
+                    &self.buffer,
+                    max_frame_size,
+                    self.deflate.is_some(),
+                )?
+            };
+            let Some((frame, consumed)) = decoded else {
+                return Ok(None);
+            };
+            self.buffer.drain(..consumed);
+            let opcode: WebSocketOpcode = *frame.get_opcode();
+            self.heartbeat.on_frame(opcode, frame.get_payload_data());
+            if opcode.is_control() {
+                return Ok(WebSocketMessage::from_payload(
+                    opcode,
+                    frame.get_payload_data().clone(),
+                ));
+            }
+            if self.message_opcode.is_none() {
+                self.message_compressed = *frame.get_rsv1();
+            }
+            if let Some(opcode) = Ws::append_fragment(
+                &frame,
+                &mut self.message,
+                &mut self.message_opcode,
+                &self.config,
+            )? {
+                let mut payload: Vec<u8> = std::mem::take(&mut self.message);
+                if self.message_compressed {
+                    payload = WebSocketDeflateConfig::decompress(
+                        &payload,
+                        self.config.get_max_ws_message_size(),
+                    )?;
+                }
+                Ws::check_text_payload(opcode, &payload)?;
+                return Ok(WebSocketMessage::from_payload(opcode, payload));
+            }
+        }
+    }
+
+    /// Answers control messages as required by the protocol.
+    ///
+    /// Pings are answered with pongs and a close frame from the peer is echoed
+    /// unless a close frame was already sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `&WebSocketMessage` - The received message.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if any required answer was sent.
+    async fn answer_control(&mut self, message: &WebSocketMessage) -> Result<(), RequestError> {
+        match message {
+            WebSocketMessage::Ping(data) if !self.close_sent => {
+                self.stream
+                    .try_send_body(WebSocketFrame::create_pong_frame(data, self.get_mask_key()))
+                    .await?;
+                self.stream.try_flush().await?;
+            }
+            WebSocketMessage::Close { code, .. } => {
+                self.close_received = true;
+                if !self.close_sent {
+                    self.close_sent = true;
+                    let frame: ResponseBody = if *code == 1005 {
+                        WebSocketFrame::encode_ws_frame(
+                            true,
+                            WebSocketOpcode::Close,
+                            self.get_mask_key(),
+                            [],
+                        )
+                    } else {
+                        WebSocketFrame::create_close_frame(*code, "", self.get_mask_key())
+                    };
+                    self.stream.try_send_body(frame).await?;
+                    self.stream.try_flush().await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Reads more data into the buffer, sending pings as the heartbeat requires.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<usize>, RequestError>` - The number of bytes read, `None` if the
+    ///   read timed out and a ping may have been sent, or an error if the peer timed out.
+    async fn read_with_heartbeat(&mut self) -> Result<Option<usize>, RequestError> {
+        self.buffer.reserve(self.config.get_buffer_size());
+        let duration: Duration = match self.heartbeat.get_timeout() {
+            Some(duration) => duration,
+            None => {
+                let size: usize = self
+                    .stream
+                    .read_mut()
+                    .await
+                    .read_buf(&mut self.buffer)
+                    .await?;
+                return Ok(Some(size));
+            }
+        };
+        let mut stream: RwLockWriteGuard<'_, R> = self.stream.read_mut().await;
+        if let Ok(result) = timeout(duration, stream.read_buf(&mut self.buffer)).await {
+            return Ok(Some(result?));
+        }
+        drop(stream);
+        if let Some(payload) = self.heartbeat.on_timeout()?
+            && !self.close_sent
+        {
+            self.stream
+                .try_send_body(WebSocketFrame::create_ping_frame(
+                    payload,
+                    self.get_mask_key(),
+                ))
+                .await?;
+            self.stream.try_flush().await?;
+        }
+        Ok(None)
+    }
+
+    /// Reads the next message from the connection.
+    ///
+    /// Pings are answered automatically and are still returned to the caller. A close
+    /// frame from the peer is echoed and returned as `Close`, after which `None` is
+    /// returned. While waiting, pings are sent according to the `WebSocketConfig`. On a
+    /// protocol violation or a heartbeat timeout a close frame with the matching status
+    /// code is sent before the error is returned.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<WebSocketMessage>, RequestError>` - The next message, `None` once
+    ///   the close handshake has finished, or an error.
+    pub async fn next_message(&mut self) -> Result<Option<WebSocketMessage>, RequestError> {
+        if self.close_received {
+            return Ok(None);
+        }
+        loop {
+            match self.decode_message() {
+                Ok(Some(message)) => {
+                    self.answer_control(&message).await?;
+                    return Ok(Some(message));
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    self.close_received = true;
+                    let _ = self.close(Self::get_error_close_code(&error), "").await;
+                    return Err(error);
+                }
+            }
+            let size: usize = match self.read_with_heartbeat().await {
+                Ok(Some(size)) => size,
+                Ok(None) => continue,
+                Err(error) => {
+                    self.close_received = true;
+                    let _ = self.close(Self::get_error_close_code(&error), "").await;
+                    return Err(error);
+                }
+            };
+            if size == 0 {
+                self.close_received = true;
+                return Err(RequestError::IncompleteWebSocketFrame(
+                    HttpStatus::BadRequest,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+
+/// Reader that yields typed WebSocket messages from a stream.
+///
+/// Pings are answered with pongs automatically and the close handshake is
+/// completed before the reader reports the end of the connection. Messages can
+/// also be sent through the reader, masked when it is the client side.
+#[derive(Debug, Getter)]
+pub struct WebSocketReader<R, W> {
+    /// The stream messages are read from and control frames are written to.
+    pub(super) stream: ArcRwLockStream<R, W>,
+    /// Configuration providing the buffer size and WebSocket size limits.
+    #[get(type(copy))]
+    pub(super) config: RequestConfig,
+    /// Bytes read from the stream that have not been decoded yet.
+    #[get(skip)]
+    pub(super) buffer: Vec<u8>,
+    /// The payload of the fragmented message being assembled.
+    #[get(skip)]
+    pub(super) message: Vec<u8>,
+    /// The opcode of the fragmented message being assembled, if any.
+    #[get(skip)]
+    pub(super) message_opcode: Option<WebSocketOpcode>,
+    /// Whether the fragmented message being assembled is compressed.
+    #[get(skip)]
+    pub(super) message_compressed: bool,
+    /// The negotiated `permessage-deflate` configuration, if the extension is enabled.
+    #[get(type(copy))]
+    pub(super) deflate: Option<WebSocketDeflateConfig>,
+    /// The heartbeat sending pings and detecting dead or idle peers.
+    #[get(type(copy))]
+    pub(super) heartbeat: WebSocketHeartbeat,
+    /// Whether this is the client side of the connection, which masks the frames it sends.
+    #[get(type(copy))]
+    pub(super) is_client: bool,
+    /// Whether a close frame has been sent to the peer.
+    #[get(type(copy))]
+    pub(super) close_sent: bool,
+    /// Whether a close frame has been received from the peer.
+    #[get(type(copy))]
+    pub(super) close_received: bool,
+}
@@ -0,0 +1,172 @@
+use crate::*;
+
+use tokio::io::{DuplexStream, duplex};
+
+fn client_frame(opcode: WebSocketOpcode, fin: bool, payload: &[u8]) -> ResponseBody {
+    WebSocketFrame::encode_ws_frame(fin, opcode, Some([7, 7, 7, 7]), payload)
+}
+
+fn reader_pair() -> (
+    DuplexStream,
+    WebSocketReader<ReadHalf<DuplexStream>, WriteHalf<DuplexStream>>,
+) {
+    let (client, server): (DuplexStream, DuplexStream) = duplex(4096);
+    let stream: ArcRwLockIoStream<DuplexStream> = ArcRwLockStream::from_io(server);
+    (
+        client,
+        WebSocketReader::new(stream, RequestConfig::default()),
+    )
+}
+
+#[tokio::test]
+async fn websocket_reader_messages_and_auto_pong() {
+    let (mut client, mut reader) = reader_pair();
+    let frames: Vec<u8> = [
+        client_frame(WebSocketOpcode::Text, false, b"hel"),
+        client_frame(WebSocketOpcode::Ping, true, b"p"),
+        client_frame(WebSocketOpcode::Continuation, true, b"lo"),
+        client_frame(WebSocketOpcode::Binary, true, &[1, 2]),
+    ]
+    .concat();
+    client.write_all(&frames).await.unwrap();
+    assert_eq!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Ping(b"p".to_vec()))
+    );
+    let mut pong: Vec<u8> = vec![0; 3];
+    client.read_exact(&mut pong).await.unwrap();
+    assert_eq!(pong, WebSocketFrame::create_pong_frame(b"p", None));
+    assert_eq!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Text("hello".to_string()))
+    );
+    assert_eq!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Binary(vec![1, 2]))
+    );
+}
+
+#[tokio::test]
+async fn websocket_reader_echoes_close() {
+    let (mut client, mut reader) = reader_pair();
+    let mut payload: Vec<u8> = 1000u16.to_be_bytes().to_vec();
+    payload.extend_from_slice(b"bye");
+    client
+        .write_all(&client_frame(WebSocketOpcode::Close, true, &payload))
+        .await
+        .unwrap();
+    assert_eq!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Close {
+            code: 1000,
+            reason: "bye".to_string()
+        })
+    );
+    assert!(reader.is_closed());
+    let mut echo: Vec<u8> = vec![0; 4];
+    client.read_exact(&mut echo).await.unwrap();
+    assert_eq!(echo, vec![0x88, 2, 0x03, 0xE8]);
+    assert_eq!(reader.next_message().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn websocket_reader_server_initiated_close() {
+    let (mut client, mut reader) = reader_pair();
+    reader.close(1001, "going away").await.unwrap();
+    let mut sent: Vec<u8> = vec![0; 14];
+    client.read_exact(&mut sent).await.unwrap();
+    assert_eq!(
+        sent,
+        WebSocketFrame::create_close_frame(1001, "going away", None)
+    );
+    client
+        .write_all(&client_frame(WebSocketOpcode::Text, true, b"late"))
+        .await
+        .unwrap();
+    client
+        .write_all(&client_frame(
+            WebSocketOpcode::Close,
+            true,
+            &1001u16.to_be_bytes(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Text("late".to_string()))
+    );
+    assert!(reader.next_message().await.unwrap().unwrap().is_close());
+    assert!(reader.is_closed());
+}
+
+#[tokio::test]
+async fn websocket_reader_fails_connection_on_protocol_error() {
+    let (mut client, mut reader) = reader_pair();
+    client
+        .write_all(&client_frame(WebSocketOpcode::Text, true, &[0xFF]))
+        .await
+        .unwrap();
+    assert_eq!(
+        reader.next_message().await,
+        Err(RequestError::WebSocketInvalidUtf8(HttpStatus::BadRequest))
+    );
+    let mut close: Vec<u8> = vec![0; 4];
+    client.read_exact(&mut close).await.unwrap();
+    assert_eq!(close, vec![0x88, 2, 0x03, 0xEF]);
+}
+
+#[test]
+fn websocket_message_to_frame_list() {
+    let frames: Vec<ResponseBody> = WebSocketMessage::Text("hi".to_string()).to_frame_list(None);
+    assert_eq!(frames, vec![vec![0x81, 2, b'h', b'i']]);
+    let frames: Vec<ResponseBody> =
+        WebSocketMessage::Binary(vec![0; MAX_FRAME_SIZE + 1]).to_frame_list(None);
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[1][0], 0x80);
+}
+
+#[tokio::test]
+async fn websocket_reader_decompresses_deflate_messages() {
+    let (mut client, mut reader) = reader_pair();
+    reader.set_deflate(WebSocketDeflateConfig::no_context_takeover());
+    let message: WebSocketMessage = WebSocketMessage::Text("hello hello hello".to_string());
+    let frames: Vec<u8> = message.to_deflate_frame_list(Some([1, 2, 3, 4])).concat();
+    assert_eq!(frames[0] & 0x40, 0x40);
+    client.write_all(&frames).await.unwrap();
+    assert_eq!(reader.next_message().await.unwrap(), Some(message));
+    let uncompressed: Vec<u8> = WebSocketMessage::Binary(vec![9; 3])
+        .to_frame_list(Some([1, 2, 3, 4]))
+        .concat();
+    client.write_all(&uncompressed).await.unwrap();
+    assert_eq!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Binary(vec![9; 3]))
+    );
+}
+
+#[tokio::test]
+async fn websocket_reader_heartbeat_ping_and_rtt() {
+    let (mut client, mut reader) = reader_pair();
+    let mut ws_config: WebSocketConfig = WebSocketConfig::default();
+    ws_config.set_ping_interval_ms(10).set_pong_timeout_ms(5000);
+    reader.set_ws_config(ws_config);
+    let client_task: tokio::task::JoinHandle<DuplexStream> = tokio::spawn(async move {
+        let mut ping: Vec<u8> = vec![0; 10];
+        client.read_exact(&mut ping).await.unwrap();
+        assert_eq!(ping[0], 0x89);
+        client
+            .write_all(&client_frame(WebSocketOpcode::Pong, true, &ping[2..]))
+            .await
+            .unwrap();
+        client
+    });
+    assert!(matches!(
+        reader.next_message().await.unwrap(),
+        Some(WebSocketMessage::Pong(_))
+    ));
+    let _client: DuplexStream = client_task.await.unwrap();
+    let stats: WebSocketHeartbeatStats = reader.get_heartbeat_stats();
+    assert_eq!(stats.get_pings_sent(), 1);
+    assert_eq!(stats.get_pongs_received(), 1);
+    assert!(stats.get_last_rtt().is_some());
+}
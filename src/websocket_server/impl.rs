@@ -0,0 +1,143 @@
+use crate::*;
+
+impl WebSocketServerHandshake {
+    /// Selects the subprotocol to use for a request.
+    ///
+    /// The server preference order decides, so the first supported protocol the
+    /// client also offered is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Request` - The upgrade request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The selected subprotocol, or `None` if there is no match.
+    pub fn select_protocol(&self, request: &Request) -> Option<String> {
+        let offered: Vec<String> = request.get_ws_protocols();
+        self.protocols
+            .iter()
+            .find(|protocol: &&String| offered.contains(protocol))
+            .cloned()
+    }
+
+    /// Checks whether a request is a well-formed WebSocket upgrade request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Request` - The request to check.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the method is GET, the upgrade type is WebSocket, the `Connection`
+    ///   header contains `upgrade` and a 16 byte `Sec-WebSocket-Key` is present.
+    fn is_upgrade_request(request: &Request) -> bool {
+        let has_connection_upgrade: bool = request
+            .try_get_header(CONNECTION)
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|value: &String| value.split(COMMA))
+            .any(|token: &str| token.trim().eq_ignore_ascii_case(UPGRADE));
+        let has_valid_key: bool = request
+            .try_get_header_back(SEC_WEBSOCKET_KEY)
+            .is_some_and(|key: String| key.len() == 24 && key.ends_with("=="));
+        request.get_method().is_get()
+            && request.get_upgrade_type().is_ws()
+            && has_connection_upgrade
+            && has_valid_key
+    }
+
+    /// Creates a response with the given status and its reason phrase.
+    ///
+    /// # Arguments
+    ///
+    /// - `HttpStatus` - The response status.
+    ///
+    /// # Returns
+    ///
+    /// - `Response` - The response without headers.
+    fn status_response(status: HttpStatus) -> Response {
+        let mut response: Response = Response::default();
+        response.set_status_code(status.code());
+        response.set_reason_phrase(status.to_string());
+        response
+    }
+
+    /// Negotiates the opening handshake for an upgrade request.
+    ///
+    /// Requests that are not WebSocket upgrades are answered with `400 Bad Request`. A
+    /// missing or unsupported `Sec-WebSocket-Version` is answered with `426 Upgrade Required`
+    /// listing version 13. Otherwise the `101 Switching Protocols` response carries the
+    /// accept key, the selected subprotocol and the accepted extension.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Request` - The upgrade request.
+    ///
+    /// # Returns
+    ///
+    /// - `WebSocketNegotiation` - The response to send and the negotiated parameters.
+    pub fn negotiate(&self, request: &Request) -> WebSocketNegotiation {
+        if !Self::is_upgrade_request(request) {
+            return WebSocketNegotiation {
+                response: Self::status_response(HttpStatus::BadRequest),
+                protocol: None,
+                deflate: None,
+            };
+        }
+        let is_supported_version: bool = request
+            .try_get_header_back(SEC_WEBSOCKET_VERSION)
+            .is_some_and(|version: String| version.trim() == WEBSOCKET_VERSION_13);
+        if !is_supported_version {
+            let mut response: Response = Self::status_response(HttpStatus::UpgradeRequired);
+            response
+                .set_header(UPGRADE, WEBSOCKET)
+                .set_header(SEC_WEBSOCKET_VERSION, WEBSOCKET_VERSION_13);
+            return WebSocketNegotiation {
+                response,
+                protocol: None,
+                deflate: None,
+            };
+        }
+        let protocol: Option<String> = self.select_protocol(request);
+        let deflate: Option<WebSocketDeflateConfig> = request
+            .try_get_header(SEC_WEBSOCKET_EXTENSIONS)
+            .filter(|_| self.deflate)
+            .and_then(|values: RequestHeadersValue| {
+                WebSocketDeflateConfig::negotiate(
+                    values.into_iter().collect::<Vec<String>>().join(COMMA),
+                )
+            });
+        let mut response: Response = Self::status_response(HttpStatus::SwitchingProtocols);
+        response
+            .set_header(UPGRADE, WEBSOCKET)
+            .set_header(CONNECTION, UPGRADE)
+            .set_header(
+                SEC_WEBSOCKET_ACCEPT,
+                WebSocketFrame::generate_accept_key(request.get_header_back(SEC_WEBSOCKET_KEY)),
+            );
+        if let Some(protocol) = &protocol {
+            response.set_header(SEC_WEBSOCKET_PROTOCOL, protocol);
+        }
+        if let Some(deflate) = deflate {
+            response.set_header(SEC_WEBSOCKET_EXTENSIONS, deflate.to_header_value());
+        }
+        WebSocketNegotiation {
+            response,
+            protocol,
+            deflate,
+        }
+    }
+}
+
+impl WebSocketNegotiation {
+    /// Checks whether the handshake was accepted.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the response is `101 Switching Protocols`.
+    #[inline(always)]
+    pub fn is_accepted(&self) -> bool {
+        self.response.get_status_code() == HttpStatus::SwitchingProtocols.code()
+    }
+}
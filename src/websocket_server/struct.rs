@@ -0,0 +1,28 @@
+use crate::*;
+
+/// Server side of the WebSocket opening handshake.
+///
+/// Holds the subprotocols and extensions the server supports and turns an
+/// upgrade request into the matching handshake response.
+#[derive(Clone, Data, Debug, Default, Deserialize, Eq, New, PartialEq, Serialize)]
+pub struct WebSocketServerHandshake {
+    /// The supported subprotocols, most preferred first.
+    #[set]
+    pub(super) protocols: Vec<String>,
+    /// Whether `permessage-deflate` is accepted when the client offers it.
+    #[get(type(copy))]
+    #[set]
+    pub(super) deflate: bool,
+}
+
+/// Outcome of negotiating a WebSocket opening handshake.
+#[derive(Clone, Debug, Eq, Getter, PartialEq)]
+pub struct WebSocketNegotiation {
+    /// The response to send: `101` if accepted, `400` or `426` otherwise.
+    pub(super) response: Response,
+    /// The selected subprotocol, if any.
+    pub(super) protocol: Option<String>,
+    /// The accepted `permessage-deflate` configuration, if any.
+    #[get(type(copy))]
+    pub(super) deflate: Option<WebSocketDeflateConfig>,
+}
@@ -0,0 +1,94 @@
+use crate::*;
+
+fn parse_request(data: &str) -> Request {
+    let mut parser: RequestParser = RequestParser::new(RequestConfig::default());
+    match parser.feed(data.as_bytes()).unwrap() {
+        RequestParseStatus::Complete(request, _) => *request,
+        RequestParseStatus::Partial => panic!("expected a complete request"),
+    }
+}
+
+fn upgrade_request(extra_headers: &str) -> Request {
+    parse_request(&format!(
+        "GET /ws HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: keep-alive, Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n{extra_headers}\r\n"
+    ))
+}
+
+#[test]
+fn server_handshake_selects_protocol_by_server_preference() {
+    let request: Request = upgrade_request(
+        "Sec-WebSocket-Version: 13\r\nSec-WebSocket-Protocol: mqtt, graphql-ws\r\nSec-WebSocket-Protocol: chat\r\n",
+    );
+    assert_eq!(
+        request.get_ws_protocols(),
+        vec!["mqtt", "graphql-ws", "chat"]
+    );
+    let mut handshake: WebSocketServerHandshake = WebSocketServerHandshake::default();
+    handshake.set_protocols(vec!["graphql-ws".to_string(), "mqtt".to_string()]);
+    let negotiation: WebSocketNegotiation = handshake.negotiate(&request);
+    assert!(negotiation.is_accepted());
+    assert_eq!(
+        negotiation.try_get_protocol().as_deref(),
+        Some("graphql-ws")
+    );
+    assert_eq!(negotiation.get_deflate(), None);
+    let response: &Response = negotiation.get_response();
+    assert_eq!(
+        response.get_header_back(SEC_WEBSOCKET_ACCEPT),
+        "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+    );
+    assert_eq!(
+        response.get_header_back(SEC_WEBSOCKET_PROTOCOL),
+        "graphql-ws"
+    );
+    assert!(!response.has_header(SEC_WEBSOCKET_EXTENSIONS));
+    handshake.set_protocols(vec!["stomp".to_string()]);
+    let negotiation: WebSocketNegotiation = handshake.negotiate(&request);
+    assert!(negotiation.is_accepted());
+    assert_eq!(negotiation.try_get_protocol(), &None);
+    assert!(
+        !negotiation
+            .get_response()
+            .has_header(SEC_WEBSOCKET_PROTOCOL)
+    );
+}
+
+#[test]
+fn server_handshake_version_and_request_checks() {
+    let handshake: WebSocketServerHandshake = WebSocketServerHandshake::default();
+    let negotiation: WebSocketNegotiation =
+        handshake.negotiate(&upgrade_request("Sec-WebSocket-Version: 8\r\n"));
+    assert!(!negotiation.is_accepted());
+    assert_eq!(negotiation.get_response().get_status_code(), 426);
+    assert_eq!(
+        negotiation
+            .get_response()
+            .get_header_back(SEC_WEBSOCKET_VERSION),
+        "13"
+    );
+    let negotiation: WebSocketNegotiation = handshake.negotiate(&parse_request(
+        "GET /ws HTTP/1.1\r\nHost: localhost\r\nSec-WebSocket-Version: 13\r\n\r\n",
+    ));
+    assert_eq!(negotiation.get_response().get_status_code(), 400);
+}
+
+#[test]
+fn server_handshake_deflate() {
+    let request: Request = upgrade_request(
+        "Sec-WebSocket-Version: 13\r\nSec-WebSocket-Extensions: permessage-deflate; client_max_window_bits\r\n",
+    );
+    let mut handshake: WebSocketServerHandshake = WebSocketServerHandshake::default();
+    assert_eq!(handshake.negotiate(&request).get_deflate(), None);
+    handshake.set_deflate(true);
+    let negotiation: WebSocketNegotiation = handshake.negotiate(&request);
+    assert_eq!(
+        negotiation.get_deflate(),
+        Some(WebSocketDeflateConfig::no_context_takeover())
+    );
+    assert_eq!(
+        negotiation
+            .get_response()
+            .get_header_back(SEC_WEBSOCKET_EXTENSIONS),
+        WebSocketDeflateConfig::no_context_takeover().to_header_value()
+    );
+}